@@ -1,6 +1,7 @@
 /// Configures Windows-specific resources for the application.
 ///
-/// This function checks if the target operating system is Windows. If so, it:
+/// This function checks if both the host and the target operating system are Windows, since
+/// `winres` is only a build dependency there. If so, it:
 /// 
 /// - Sets the path to the icon file.
 /// - Instructs Cargo to re-run the build script if the icon file changes.
@@ -12,7 +13,8 @@
 ///
 /// Panics if the resource compilation fails.
 fn main() {
-    if cfg!(target_os = "windows") {
+    #[cfg(windows)]
+    if std::env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("windows") {
         let icon_path = r"C:\Users\YourUsername\YourDirectory\pola\assets\pola.ico";
         // Tell Cargo to re-run the build script if the icon changes.
         println!("cargo:rerun-if-changed={}", icon_path);
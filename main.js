@@ -1060,7 +1060,7 @@ const SKIN_COLLECTION = [
     rarity: "Red",
     event: "April Fools",
     year: null,
-    tags: ["all case", "popular"],
+    tags: ["allcase", "popular"],
     img: null
   },
   {
@@ -1116,7 +1116,7 @@ const SKIN_COLLECTION = [
     rarity: "Teal",
     event: "VIP Players",
     year: null,
-    tags: ["special", "VIP"],
+    tags: ["special", "vip"],
     img: null
   },
 ].sort((a, b) => a.name.localeCompare(b.name));
@@ -1387,16 +1387,45 @@ class AppState {
     return Array.from(terms);
   }
 
+  // Ranked matches from the Rust search engine (pkg/, built from src/lib.rs), or null when it
+  // hasn't loaded and the plain substring search should be used
+  engineResults(value) {
+    if (!window.polaEngine || value.trim() === '') return null;
+    const favorites = JSON.stringify(this.skins.filter(skin => skin.favorite).map(skin => skin.name));
+    const byName = new Map(this.skins.map(skin => [skin.name, skin]));
+    return JSON.parse(window.polaEngine.search(value, favorites))
+      .map(skin => byName.get(skin.name))
+      .filter(Boolean);
+  }
+
+  // Completions from the Rust engine, which knows the query language and skips terms that are
+  // already typed, or null when it hasn't loaded and fuzzysort over all terms should be used
+  engineSuggestions(value) {
+    if (!window.polaEngine) return null;
+    const lastTerm = value.split(' ').pop().toLowerCase();
+    const favorites = JSON.stringify(this.skins.filter(skin => skin.favorite).map(skin => skin.name));
+    return JSON.parse(window.polaEngine.suggest(value, favorites)).map(term => {
+      const match = fuzzysort.single(lastTerm, term);
+      return {
+        term,
+        highlighted: match ? fuzzysort.highlight(match, '<span class="suggestion-highlight">', '</span>') : term
+      };
+    });
+  }
+
   updateSearch(value) {
     this.input = value;
     localStorage.setItem('lastSearchQuery', value);
     this.currentPage = 1;
     const terms = value.toLowerCase().split(' ').filter(t => t.trim() !== '');
 
-    this.results = this.skins.filter(skin => {
-      const searchString = `${skin.name} ${skin.rarity} ${skin.event} ${skin.year || ''} ${skin.tags.join(' ')}`.toLowerCase();
-      let termMatch = terms.every(term => searchString.includes(term));
-      if (!termMatch) return false;
+    const ranked = this.engineResults(value);
+    this.results = (ranked || this.skins).filter(skin => {
+      if (!ranked) {
+        const searchString = `${skin.name} ${skin.rarity} ${skin.event} ${skin.year || ''} ${skin.tags.join(' ')}`.toLowerCase();
+        let termMatch = terms.every(term => searchString.includes(term));
+        if (!termMatch) return false;
+      }
       
       // Tag filters
       if (this.tagFilters.include.length > 0 && !skin.tags.some(tag => this.tagFilters.include.includes(tag))) {
@@ -1453,6 +1482,12 @@ class AppState {
     this.suggestions = [];
 
     if (lastTerm) {
+      const suggested = this.engineSuggestions(this.input);
+      if (suggested) {
+        this.suggestions = suggested;
+        return;
+      }
+
       if (this.suggestionsCache[lastTerm]) {
         this.suggestions = this.suggestionsCache[lastTerm];
         return;
@@ -2443,6 +2478,15 @@ document.addEventListener('DOMContentLoaded', () => {
   const app = new AppState();
   window.app = app;
 
+  // Optional: `wasm-pack build --target web -- --features wasm` puts the Rust engine in pkg/
+  import('./pkg/pola.js')
+    .then(async engine => {
+      await engine.default();
+      window.polaEngine = new engine.SearchEngine();
+      app.updateSearch(app.input);
+    })
+    .catch(err => console.warn('Rust search engine unavailable, using the built-in search', err));
+
   document.getElementById('help-btn').addEventListener('click', () => {
     const modal = document.getElementById('help-modal');
     modal.style.display = 'flex';
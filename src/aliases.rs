@@ -0,0 +1,88 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use crate::{
+    config::config_file,
+    search::{filter_term, split_filter, split_query},
+};
+
+/// Trader nicknames shipped with the catalog, as `(alias, canonical)` pairs. Queries are searched
+/// as they're typed, so these shouldn't be the start of a word people type on the way to
+/// another one.
+const BUILTIN_ALIASES: &[(&str, &str)] = &[
+    ("vl", "void lord"),
+    ("peri", "periastron"),
+    ("xmas", "christmas"),
+    ("val", "valentine"),
+    ("bday", "birthday"),
+    ("hween", "halloween"),
+    ("exq", "exquisite"),
+];
+
+pub fn get_aliases_path() -> PathBuf {
+    config_file("aliases.json")
+}
+
+pub fn builtin_aliases() -> HashMap<String, String> {
+    BUILTIN_ALIASES
+        .iter()
+        .map(|&(alias, canonical)| (alias.to_string(), canonical.to_string()))
+        .collect()
+}
+
+/// Loads the built-in aliases plus the user's own from `aliases.json`, which take precedence.
+pub fn load_aliases() -> HashMap<String, String> {
+    let mut aliases = builtin_aliases();
+
+    if let Ok(content) = fs::read_to_string(get_aliases_path()) {
+        if let Ok(user) = serde_json::from_str::<HashMap<String, String>>(&content) {
+            for (alias, canonical) in user {
+                aliases.insert(alias.to_lowercase(), canonical.to_lowercase());
+            }
+        }
+    }
+    aliases
+}
+
+/// Replaces every whole-word alias in `query` with its canonical term, including each value of
+/// `field:a,b` filters. Regex terms are left untouched.
+pub fn expand_aliases(query: &str, aliases: &HashMap<String, String>) -> String {
+    split_query(query)
+        .into_iter()
+        .map(|part| {
+            if part.starts_with('/') && part.ends_with('/') {
+                return part.to_string();
+            }
+            if let Some((field, values)) = split_filter(part) {
+                if !values.iter().any(|value| aliases.contains_key(value)) {
+                    return part.to_string();
+                }
+                let values: Vec<&str> = values
+                    .iter()
+                    .map(|value| aliases.get(value).unwrap_or(value).as_str())
+                    .collect();
+                // The field as typed, since `split_filter` lowercases it
+                let field = part.split_once(':').map_or(field.as_str(), |(field, _)| field);
+                return filter_term(field, &values);
+            }
+            aliases.get(&part.to_lowercase()).cloned().unwrap_or_else(|| part.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_words_and_each_filter_value() {
+        let aliases = builtin_aliases();
+        let expand = |query| expand_aliases(query, &aliases);
+        assert_eq!(expand("vl peri"), "void lord periastron");
+        assert_eq!(expand("rarity:pink event:xmas,val"), "rarity:pink event:christmas,valentine");
+        assert_eq!(expand("-event:\"xmas\""), "-event:christmas");
+        assert_eq!(expand("tag:popular /vl/"), "tag:popular /vl/");
+        // Words people type on the way to another one stay as they are
+        assert_eq!(expand("cr ad ss"), "cr ad ss");
+    }
+}
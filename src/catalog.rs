@@ -1,38 +1,80 @@
-use crate::models::{Skin, TermInfo};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-pub fn load_all_terms(skins: &[Skin]) -> HashMap<String, TermInfo> {
-    let mut terms = HashMap::new();
-    for skin in skins {
-        // Name words
-        for word in skin.name_lower.split_whitespace() {
-            let entry: &mut TermInfo = terms.entry(word.to_string()).or_default();
-            entry.is_name = true;
-        }
-        // Event words
-        for word in skin.event_lower.split_whitespace() {
-            let entry: &mut TermInfo = terms.entry(word.to_string()).or_default();
-            entry.is_event = true;
-        }
-        // Rarity
-        let entry: &mut TermInfo = terms.entry(skin.rarity_lower.clone()).or_default();
-        entry.is_rarity = true;
-        // Tags
-        for tag in &skin.tags_lower {
-            let entry: &mut TermInfo = terms.entry(tag.clone()).or_default();
-            entry.is_tag = true;
-        }
-        // Year
-        if !skin.year_str.is_empty() {
-            let entry: &mut TermInfo = terms.entry(skin.year_str.clone()).or_default();
-            entry.is_year = true;
-        }
+#[derive(Debug, Clone)]
+pub struct Skin {
+    pub name: String,
+    pub name_lower: String,
+    pub rarity: String,
+    pub rarity_lower: String,
+    pub event: String,
+    pub event_lower: String,
+    pub year: Option<u32>,
+    pub year_str: String,
+    pub tags: Vec<String>,
+    pub tags_lower: HashSet<String>,
+}
+
+/// Rarity from most common to rarest, so sorting follows tiers instead of the alphabet.
+pub fn rarity_tier(rarity_lower: &str) -> u8 {
+    match rarity_lower {
+        "red" => 1,
+        "pink" => 2,
+        "teal" => 3,
+        _ => 0,
     }
-    terms
 }
 
+/// Rarities from most common to rarest, as the catalog spells them.
+pub const RARITIES: &[&str] = &["Red", "Pink", "Teal"];
+
+/// Every event a skin can come from. Case events may also end in ` (Exquisite)`.
+pub const EVENTS: &[&str] = &[
+    "Animal Case",
+    "April Fools",
+    "Artist",
+    "Birthday Case",
+    "Builder",
+    "Camouflage Case",
+    "Christmas Case",
+    "Christmas Event",
+    "Code",
+    "Easter Case",
+    "Easter Event",
+    "Exquisite Case",
+    "Future Case",
+    "Halloween Case",
+    "Launch",
+    "Material Case",
+    "Nature Case",
+    "Pattern Case",
+    "Pre-release Tester",
+    "Refined Case",
+    "Summer Bundle",
+    "Summer Case",
+    "VIP Players",
+    "Valentine Bundle",
+    "Valentine Case",
+    "Worthy Individuals",
+];
+
 pub fn load_skins() -> Vec<Skin> {
-    let mut skins = vec![
+    let mut skins = catalog_entries();
+
+    // Post-process to populate derived fields
+    for skin in &mut skins {
+        skin.name_lower = skin.name.to_lowercase();
+        skin.rarity_lower = skin.rarity.to_lowercase();
+        skin.event_lower = skin.event.to_lowercase();
+        skin.year_str = skin.year.map(|y| y.to_string()).unwrap_or_default();
+        skin.tags_lower = skin.tags.iter().map(|t| t.to_lowercase()).collect();
+    }
+
+    skins
+}
+
+/// The catalog as written, before `load_skins` derives the lowercase fields from the rest.
+pub fn catalog_entries() -> Vec<Skin> {
+    vec![
         // Valentine Case
         Skin {
             name: "Cupid".to_string(),
@@ -44,10 +86,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Rainbow Periastron".to_string(),
@@ -58,19 +97,11 @@ pub fn load_skins() -> Vec<Skin> {
             event_lower: "valentine case (exquisite)".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec![
-                "case".to_string(),
-                "exquisite".to_string(),
-                "periastron".to_string(),
-            ],
-            tags_lower: vec![
-                "case".to_string(),
-                "exquisite".to_string(),
-                "periastron".to_string(),
-            ]
-            .into_iter()
-            .map(|t| t.to_lowercase())
-            .collect(),
+            tags: vec!["case".to_string(), "exquisite".to_string(), "periastron".to_string()],
+            tags_lower: vec!["case".to_string(), "exquisite".to_string(), "periastron".to_string()]
+                .into_iter()
+                .map(|t| t.to_lowercase())
+                .collect(),
         },
         Skin {
             name: "Crimson Periastron".to_string(),
@@ -97,10 +128,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Ivory Periastron".to_string(),
@@ -111,19 +139,11 @@ pub fn load_skins() -> Vec<Skin> {
             event_lower: "valentine case (exquisite)".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec![
-                "case".to_string(),
-                "exquisite".to_string(),
-                "periastron".to_string(),
-            ],
-            tags_lower: vec![
-                "case".to_string(),
-                "exquisite".to_string(),
-                "periastron".to_string(),
-            ]
-            .into_iter()
-            .map(|t| t.to_lowercase())
-            .collect(),
+            tags: vec!["case".to_string(), "exquisite".to_string(), "periastron".to_string()],
+            tags_lower: vec!["case".to_string(), "exquisite".to_string(), "periastron".to_string()]
+                .into_iter()
+                .map(|t| t.to_lowercase())
+                .collect(),
         },
         Skin {
             name: "Diamond".to_string(),
@@ -166,10 +186,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Hellfire".to_string(),
@@ -181,10 +198,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Surge".to_string(),
@@ -240,8 +254,8 @@ pub fn load_skins() -> Vec<Skin> {
             event_lower: "birthday case".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string()]
+            tags: vec!["case".to_string(), "periastron".to_string(), "popular".to_string()],
+            tags_lower: vec!["case".to_string(), "periastron".to_string(), "popular".to_string()]
                 .into_iter()
                 .map(|t| t.to_lowercase())
                 .collect(),
@@ -257,10 +271,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Amethyst Periastron".to_string(),
@@ -287,10 +298,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Guitar".to_string(),
@@ -302,10 +310,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Joyful Periastron".to_string(),
@@ -348,10 +353,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Mystic".to_string(),
@@ -393,10 +395,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Cythrex".to_string(),
@@ -408,10 +407,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Dog".to_string(),
@@ -438,10 +434,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Ghostfire".to_string(),
@@ -453,10 +446,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Inscription".to_string(),
@@ -468,10 +458,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Mummy".to_string(),
@@ -483,10 +470,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Retrowave".to_string(),
@@ -498,10 +482,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Shikai".to_string(),
@@ -513,10 +494,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Halloween Case
         Skin {
@@ -544,10 +522,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Dusekkar".to_string(),
@@ -558,8 +533,8 @@ pub fn load_skins() -> Vec<Skin> {
             event_lower: "halloween case".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
+            tags: vec!["case".to_string(), "popular".to_string()],
+            tags_lower: vec!["case".to_string(), "popular".to_string()]
                 .into_iter()
                 .map(|t| t.to_lowercase())
                 .collect(),
@@ -574,10 +549,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Dracula".to_string(),
@@ -589,10 +561,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Hallowing".to_string(),
@@ -604,10 +573,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Orange Energy".to_string(),
@@ -634,10 +600,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Christmas Case
         Skin {
@@ -650,10 +613,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Icycle".to_string(),
@@ -665,10 +625,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Santa".to_string(),
@@ -680,10 +637,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Candy Energy".to_string(),
@@ -725,10 +679,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Snowman".to_string(),
@@ -740,10 +691,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Easter Event
         Skin {
@@ -764,8 +712,8 @@ pub fn load_skins() -> Vec<Skin> {
         Skin {
             name: "Corrupted".to_string(),
             name_lower: "corrupted".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
+            rarity: "Teal".to_string(),
+            rarity_lower: "teal".to_string(),
             event: "Easter Event".to_string(),
             event_lower: "easter event".to_string(),
             year: Some(2023),
@@ -786,10 +734,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2024),
             year_str: "2024".to_string(),
             tags: vec!["event".to_string()],
-            tags_lower: vec!["event".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["event".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Summer Bundle
         Skin {
@@ -802,10 +747,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2023),
             year_str: "2023".to_string(),
             tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Cyberlight".to_string(),
@@ -817,10 +759,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2023),
             year_str: "2023".to_string(),
             tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Frostburn".to_string(),
@@ -832,10 +771,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2023),
             year_str: "2023".to_string(),
             tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Inferno Angel".to_string(),
@@ -862,10 +798,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2024),
             year_str: "2024".to_string(),
             tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Darkness".to_string(),
@@ -877,10 +810,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2024),
             year_str: "2024".to_string(),
             tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Vilethorn".to_string(),
@@ -892,10 +822,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2024),
             year_str: "2024".to_string(),
             tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Winged".to_string(),
@@ -923,10 +850,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2025),
             year_str: "2025".to_string(),
             tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Love Scepter".to_string(),
@@ -1044,10 +968,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: Some(2023),
             year_str: "2023".to_string(),
             tags: vec!["event".to_string()],
-            tags_lower: vec!["event".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["event".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Iceblade".to_string(),
@@ -1070,12 +991,12 @@ pub fn load_skins() -> Vec<Skin> {
             name_lower: "bubbles".to_string(),
             rarity: "Teal".to_string(),
             rarity_lower: "teal".to_string(),
-            event: "Code Redeemed".to_string(),
-            event_lower: "code redeemed".to_string(),
+            event: "Code".to_string(),
+            event_lower: "code".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec!["code".to_string()],
-            tags_lower: vec!["code".to_string()]
+            tags: vec!["code".to_string(), "gamenight".to_string()],
+            tags_lower: vec!["code".to_string(), "gamenight".to_string()]
                 .into_iter()
                 .map(|t| t.to_lowercase())
                 .collect(),
@@ -1085,8 +1006,8 @@ pub fn load_skins() -> Vec<Skin> {
             name_lower: "butter".to_string(),
             rarity: "Teal".to_string(),
             rarity_lower: "teal".to_string(),
-            event: "Code Redeemed".to_string(),
-            event_lower: "code redeemed".to_string(),
+            event: "Code".to_string(),
+            event_lower: "code".to_string(),
             year: None,
             year_str: "".to_string(),
             tags: vec!["code".to_string(), "duped".to_string()],
@@ -1100,27 +1021,24 @@ pub fn load_skins() -> Vec<Skin> {
             name_lower: "fireworks".to_string(),
             rarity: "Teal".to_string(),
             rarity_lower: "teal".to_string(),
-            event: "Code Redeemed".to_string(),
-            event_lower: "code redeemed".to_string(),
+            event: "Code".to_string(),
+            event_lower: "code".to_string(),
             year: None,
             year_str: "".to_string(),
             tags: vec!["code".to_string()],
-            tags_lower: vec!["code".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["code".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Pearl".to_string(),
             name_lower: "pearl".to_string(),
             rarity: "Teal".to_string(),
             rarity_lower: "teal".to_string(),
-            event: "Code Redeemed".to_string(),
-            event_lower: "code redeemed".to_string(),
+            event: "Code".to_string(),
+            event_lower: "code".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec!["code".to_string()],
-            tags_lower: vec!["code".to_string()]
+            tags: vec!["code".to_string(), "gamenight".to_string()],
+            tags_lower: vec!["code".to_string(), "gamenight".to_string()]
                 .into_iter()
                 .map(|t| t.to_lowercase())
                 .collect(),
@@ -1130,12 +1048,12 @@ pub fn load_skins() -> Vec<Skin> {
             name_lower: "tin".to_string(),
             rarity: "Teal".to_string(),
             rarity_lower: "teal".to_string(),
-            event: "Code Redeemed".to_string(),
-            event_lower: "code redeemed".to_string(),
+            event: "Code".to_string(),
+            event_lower: "code".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec!["code".to_string()],
-            tags_lower: vec!["code".to_string()]
+            tags: vec!["code".to_string(), "gamenight".to_string()],
+            tags_lower: vec!["code".to_string(), "gamenight".to_string()]
                 .into_iter()
                 .map(|t| t.to_lowercase())
                 .collect(),
@@ -1151,10 +1069,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["launch".to_string()],
-            tags_lower: vec!["launch".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["launch".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Exquisite Case (Pinks & Reds)
         Skin {
@@ -1181,8 +1096,8 @@ pub fn load_skins() -> Vec<Skin> {
             event_lower: "exquisite case".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
+            tags: vec!["case".to_string(), "exquisite".to_string(), "popular".to_string()],
+            tags_lower: vec!["case".to_string(), "exquisite".to_string(), "popular".to_string()]
                 .into_iter()
                 .map(|t| t.to_lowercase())
                 .collect(),
@@ -1348,10 +1263,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Unicorn".to_string(),
@@ -1363,10 +1275,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Bacon".to_string(),
@@ -1378,10 +1287,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Salmon".to_string(),
@@ -1408,10 +1314,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Slither".to_string(),
@@ -1423,10 +1326,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Camouflage Case
         Skin {
@@ -1439,10 +1339,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Glacial".to_string(),
@@ -1454,10 +1351,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Chartreuse Periastron".to_string(),
@@ -1484,10 +1378,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Prehistoric".to_string(),
@@ -1499,10 +1390,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Shadow".to_string(),
@@ -1514,10 +1402,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Violet Energy".to_string(),
@@ -1545,14 +1430,11 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
-            name: "Orinthan".to_string(),
-            name_lower: "orinthan".to_string(),
+            name: "Orinthian".to_string(),
+            name_lower: "orinthian".to_string(),
             rarity: "Pink".to_string(),
             rarity_lower: "pink".to_string(),
             event: "Future Case".to_string(),
@@ -1560,10 +1442,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Azure Periastron".to_string(),
@@ -1590,10 +1469,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Galactic".to_string(),
@@ -1605,10 +1481,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Green Energy".to_string(),
@@ -1635,10 +1508,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Omega".to_string(),
@@ -1650,10 +1520,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Material Case
         Skin {
@@ -1666,10 +1533,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Adurite".to_string(),
@@ -1681,10 +1545,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Bluesteel".to_string(),
@@ -1696,10 +1557,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Wooden".to_string(),
@@ -1711,10 +1569,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Nature Case
         Skin {
@@ -1727,10 +1582,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Elven".to_string(),
@@ -1742,10 +1594,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Molten".to_string(),
@@ -1757,10 +1606,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Autumnal".to_string(),
@@ -1772,10 +1618,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Beach".to_string(),
@@ -1787,10 +1630,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Breeze".to_string(),
@@ -1802,10 +1642,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Earth".to_string(),
@@ -1817,10 +1654,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Ocean".to_string(),
@@ -1832,10 +1666,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Pattern Case
         Skin {
@@ -1848,10 +1679,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Relic".to_string(),
@@ -1863,10 +1691,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Sorcus".to_string(),
@@ -1878,10 +1703,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         // Refined Case
         Skin {
@@ -1894,10 +1716,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Breaker".to_string(),
@@ -1909,10 +1728,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Divine".to_string(),
@@ -1924,10 +1740,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Enforcer".to_string(),
@@ -1939,10 +1752,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Frosted".to_string(),
@@ -1954,10 +1764,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Hunter".to_string(),
@@ -1969,10 +1776,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Neon".to_string(),
@@ -1984,10 +1788,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Pharaoh".to_string(),
@@ -1999,10 +1800,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Skyward".to_string(),
@@ -2014,10 +1812,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "Steampunk".to_string(),
@@ -2029,10 +1824,7 @@ pub fn load_skins() -> Vec<Skin> {
             year: None,
             year_str: "".to_string(),
             tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
+            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
         },
         Skin {
             name: "No Dagger".to_string(),
@@ -2040,83 +1832,104 @@ pub fn load_skins() -> Vec<Skin> {
             rarity: "Red".to_string(),
             rarity_lower: "red".to_string(),
             event: "April Fools".to_string(),
-            event_lower: "April Fools".to_string(),
+            event_lower: "april fools".to_string(),
             year: None,
             year_str: "".to_string(),
-            tags: vec!["all case".to_string()],
-            tags_lower: vec!["all case".to_string()]
+            tags: vec!["allcase".to_string(), "popular".to_string()],
+            tags_lower: vec!["allcase".to_string(), "popular".to_string()]
                 .into_iter()
                 .map(|t| t.to_lowercase())
                 .collect(),
         },
-    ];
-
-    // Post-process to populate derived fields
-    for skin in &mut skins {
-        skin.name_lower = skin.name.to_lowercase();
-        skin.rarity_lower = skin.rarity.to_lowercase();
-        skin.event_lower = skin.event.to_lowercase();
-        skin.year_str = skin.year.map(|y| y.to_string()).unwrap_or_default();
-        skin.tags_lower = skin.tags.iter().map(|t| t.to_lowercase()).collect();
-    }
-
-    skins
-}
-
-pub fn search_skins(
-    skins: &[Skin],
-    name_map: &HashMap<String, usize>,
-    tags: &HashSet<&str>,
-) -> Vec<Skin> {
-    let exact_matches: Vec<Skin> = tags
-        .iter()
-        .filter_map(|tag| name_map.get(*tag))
-        .map(|&i| skins[i].clone())
-        .collect();
-    if !exact_matches.is_empty() {
-        return exact_matches;
-    }
-
-    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
-    let mut scored_skins: Vec<(i64, &Skin)> = skins
-        .iter()
-        .filter_map(|skin| {
-            let all_tags_matched = tags.iter().all(|&tag| {
-                skin.name_lower.contains(tag)
-                    || skin.rarity_lower == tag
-                    || skin.event_lower.contains(tag)
-                    || skin.year_str == tag
-                    || skin.tags_lower.contains(tag)
-            });
-            if !all_tags_matched {
-                return None;
-            }
-
-            let mut score = 0;
-            for tag in tags {
-                if skin.name_lower.contains(tag) {
-                    score += 100;
-                }
-                if skin.rarity_lower == *tag {
-                    score += 80;
-                }
-                if skin.event_lower.contains(tag) {
-                    score += 60;
-                }
-                if let Some(s) = matcher.fuzzy_match(&skin.name_lower, tag) {
-                    score += s;
-                }
-                if skin.tags_lower.contains(&tag.to_string()) {
-                    score += 40;
-                }
-                if skin.year_str == *tag {
-                    score += 20;
-                }
-            }
-            Some((score, skin))
-        })
-        .collect();
-
-    scored_skins.sort_by(|a, b| b.0.cmp(&a.0));
-    scored_skins.into_iter().map(|(_, s)| s.clone()).collect()
+        Skin {
+            name: "Whiteheart".to_string(),
+            name_lower: "whiteheart".to_string(),
+            rarity: "Teal".to_string(),
+            rarity_lower: "teal".to_string(),
+            event: "Worthy Individuals".to_string(),
+            event_lower: "worthy individuals".to_string(),
+            year: None,
+            year_str: "".to_string(),
+            tags: vec!["special".to_string(), "rare".to_string()],
+            tags_lower: vec!["special".to_string(), "rare".to_string()]
+                .into_iter()
+                .map(|t| t.to_lowercase())
+                .collect(),
+        },
+        Skin {
+            name: "Darkheart".to_string(),
+            name_lower: "darkheart".to_string(),
+            rarity: "Teal".to_string(),
+            rarity_lower: "teal".to_string(),
+            event: "Worthy Individuals".to_string(),
+            event_lower: "worthy individuals".to_string(),
+            year: None,
+            year_str: "".to_string(),
+            tags: vec!["special".to_string()],
+            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
+        },
+        Skin {
+            name: "Banana".to_string(),
+            name_lower: "banana".to_string(),
+            rarity: "Teal".to_string(),
+            rarity_lower: "teal".to_string(),
+            event: "Pre-release Tester".to_string(),
+            event_lower: "pre-release tester".to_string(),
+            year: Some(2021),
+            year_str: "2021".to_string(),
+            tags: vec!["special".to_string()],
+            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
+        },
+        Skin {
+            name: "Hammer".to_string(),
+            name_lower: "hammer".to_string(),
+            rarity: "Teal".to_string(),
+            rarity_lower: "teal".to_string(),
+            event: "Builder".to_string(),
+            event_lower: "builder".to_string(),
+            year: None,
+            year_str: "".to_string(),
+            tags: vec!["special".to_string()],
+            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
+        },
+        Skin {
+            name: "Paintbrush".to_string(),
+            name_lower: "paintbrush".to_string(),
+            rarity: "Teal".to_string(),
+            rarity_lower: "teal".to_string(),
+            event: "Artist".to_string(),
+            event_lower: "artist".to_string(),
+            year: None,
+            year_str: "".to_string(),
+            tags: vec!["special".to_string()],
+            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
+        },
+        Skin {
+            name: "Riddling".to_string(),
+            name_lower: "riddling".to_string(),
+            rarity: "Teal".to_string(),
+            rarity_lower: "teal".to_string(),
+            event: "Worthy Individuals".to_string(),
+            event_lower: "worthy individuals".to_string(),
+            year: None,
+            year_str: "".to_string(),
+            tags: vec!["special".to_string()],
+            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
+        },
+        Skin {
+            name: "VIP".to_string(),
+            name_lower: "vip".to_string(),
+            rarity: "Teal".to_string(),
+            rarity_lower: "teal".to_string(),
+            event: "VIP Players".to_string(),
+            event_lower: "vip players".to_string(),
+            year: None,
+            year_str: "".to_string(),
+            tags: vec!["special".to_string(), "vip".to_string()],
+            tags_lower: vec!["special".to_string(), "vip".to_string()]
+                .into_iter()
+                .map(|t| t.to_lowercase())
+                .collect(),
+        },
+    ]
 }
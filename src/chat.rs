@@ -0,0 +1,94 @@
+use crate::Skin;
+
+/// Discord's message length limit, which also keeps Markdown pastes to one screen or so.
+pub const CHAT_LIMIT: usize = 2000;
+
+/// Compact one-line-per-skin output for pasting into chat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatFormat {
+    /// Rarity emoji and tags as inline code, which Discord renders as badges.
+    Discord,
+    /// A Markdown list naming the rarity, for chats without emoji.
+    Markdown,
+}
+
+impl ChatFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "discord" => Some(ChatFormat::Discord),
+            "markdown" | "md" => Some(ChatFormat::Markdown),
+            _ => None,
+        }
+    }
+}
+
+fn rarity_emoji(rarity_lower: &str) -> &'static str {
+    match rarity_lower {
+        "red" => "🔴",
+        "pink" => "🩷",
+        "teal" => "🩵",
+        _ => "⚪",
+    }
+}
+
+/// Backslash-escapes characters that chat Markdown would treat as formatting.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '~' | '`' | '|' | '\\' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// A single skin on one line, e.g. `🔴 **Void Lord** · Halloween Case · 2023 · `popular``.
+pub fn render_skin(skin: &Skin, format: ChatFormat) -> String {
+    let mut parts = vec![match format {
+        ChatFormat::Discord => {
+            format!("{} **{}**", rarity_emoji(&skin.rarity_lower), escape_markdown(&skin.name))
+        },
+        ChatFormat::Markdown => {
+            format!("- **{}** · {}", escape_markdown(&skin.name), escape_markdown(&skin.rarity))
+        },
+    }];
+    parts.push(escape_markdown(&skin.event));
+    if let Some(year) = skin.year {
+        parts.push(year.to_string());
+    }
+    if !skin.tags.is_empty() {
+        parts.push(match format {
+            ChatFormat::Discord => {
+                skin.tags.iter().map(|tag| format!("`{}`", tag)).collect::<Vec<_>>().join(" ")
+            },
+            ChatFormat::Markdown => escape_markdown(&skin.tags.join(", ")),
+        });
+    }
+    parts.join(" · ")
+}
+
+/// One line per skin, keeping to `limit` characters by ending with an "…and N more" line
+/// instead of cutting a skin in half.
+pub fn render_chat(skins: &[Skin], format: ChatFormat, limit: usize) -> String {
+    let lines: Vec<String> = skins.iter().map(|skin| render_skin(skin, format)).collect();
+    let full = lines.join("\n");
+    if full.chars().count() <= limit {
+        return full;
+    }
+
+    let footer_room = format!("\n…and {} more", lines.len()).chars().count();
+    let mut used = 0;
+    let mut kept: Vec<&str> = Vec::new();
+    for line in &lines {
+        let length = line.chars().count() + usize::from(!kept.is_empty());
+        if used + length + footer_room > limit {
+            break;
+        }
+        used += length;
+        kept.push(line);
+    }
+    let footer = format!("…and {} more", lines.len() - kept.len());
+    kept.push(&footer);
+    kept.join("\n")
+}
@@ -0,0 +1,69 @@
+//! Searching as `pola_cli` does it: every word has to appear in a skin, a skin named in full is
+//! listed first, and typos are only corrected when the query as typed finds nothing.
+
+use super::*;
+
+fn search(query: &str) -> Vec<String> {
+    let skins = load_skins();
+    let name_map: HashMap<String, usize> =
+        skins.iter().enumerate().map(|(i, skin)| (skin.name_lower.clone(), i)).collect();
+    let favorites = Favorites { skins: HashSet::new(), file_path: PathBuf::new() };
+    search_skins(&skins, &name_map, query, &favorites, &RankingProfile::default())
+        .into_iter()
+        .map(|skin| skin.name)
+        .collect()
+}
+
+#[test]
+fn every_word_has_to_match() {
+    let skins = load_skins();
+    for query in ["pink summer", "event 2023", "red periastron", "teal event", "exquisite pink"] {
+        let results = search(query);
+        assert!(!results.is_empty(), "nothing for {:?}", query);
+        for name in &results {
+            let skin = skins.iter().find(|skin| skin.name == *name).unwrap();
+            for word in query.split_whitespace() {
+                assert!(matches_tag(skin, word), "{} doesn't match {:?}", name, word);
+            }
+        }
+    }
+    assert_eq!(search("void lord"), ["Void Lord"]);
+}
+
+#[test]
+fn an_exact_name_is_listed_first() {
+    for skin in load_skins() {
+        let results = search(&skin.name_lower);
+        assert_eq!(results.first(), Some(&skin.name), "searching {:?}", skin.name_lower);
+    }
+    // Ahead of skins whose names contain it
+    assert_eq!(search("golden")[..2], ["Golden", "Goldenrod"]);
+    // But it still has to match every word
+    assert!(search("void lord teal").is_empty());
+}
+
+#[test]
+fn a_typo_is_corrected_only_when_the_query_finds_nothing() {
+    let skins = load_skins();
+    let name_map: HashMap<String, usize> =
+        skins.iter().enumerate().map(|(i, skin)| (skin.name_lower.clone(), i)).collect();
+    let favorites = Favorites { skins: HashSet::new(), file_path: PathBuf::new() };
+    let options = SearchOptions {
+        explain: false,
+        profile: RankingProfile::default(),
+        aliases: HashMap::new(),
+        rarity_weights: HashMap::new(),
+        format: None,
+    };
+    let run = |query| run_query(query, &skins, &name_map, &favorites, &options);
+
+    let (query, results) = run("cupdi");
+    assert_eq!(query, "cupid");
+    assert!(results.iter().any(|skin| skin.name == "Cupid"));
+    // What was typed wins when it matches anything
+    assert_eq!(run("void lord").0, "void lord");
+    // And a correction that finds nothing isn't offered
+    let (query, results) = run("cupdi zzzzzz");
+    assert_eq!(query, "cupdi zzzzzz");
+    assert!(results.is_empty());
+}
@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+/// `~/.skin_tui`, where settings and the user's own data live, or `None` without a home
+/// directory.
+pub fn config_dir() -> Option<PathBuf> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let home = dirs::home_dir();
+    // There's no home directory in the browser
+    #[cfg(target_arch = "wasm32")]
+    let home: Option<PathBuf> = None;
+    home.map(|home| home.join(".skin_tui"))
+}
+
+/// `name` in `config_dir`, or in `./.skin_tui` when there's no home directory.
+pub fn config_file(name: &str) -> PathBuf {
+    config_dir().unwrap_or_else(|| PathBuf::from(".skin_tui")).join(name)
+}
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    catalog::load_skins,
+    ranking::RankingProfile,
+    search::{explain_skin, load_all_terms, search_skins, suggest_terms, TermInfo},
+    Skin,
+};
+
+/// The TUI's search over the built-in catalog, with the default ranking profile and no local
+/// config, so every caller gets the same results. Results are returned as JSON strings.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct SearchEngine {
+    skins: Vec<Skin>,
+    name_map: HashMap<String, usize>,
+    all_terms: HashMap<String, TermInfo>,
+    profile: RankingProfile,
+}
+
+impl Default for SearchEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl SearchEngine {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new() -> SearchEngine {
+        let skins = load_skins();
+        let name_map = skins.iter().enumerate().map(|(i, s)| (s.name_lower.clone(), i)).collect();
+        let all_terms = load_all_terms(&skins, &HashMap::new());
+        SearchEngine { skins, name_map, all_terms, profile: RankingProfile::default() }
+    }
+
+    /// Every skin as `[{name, rarity, event, year, tags}]`, in catalog order.
+    pub fn catalog(&self) -> String {
+        skins_json(&self.skins)
+    }
+
+    /// Ranked results for `query`, in the same shape as `catalog`. `favorites` is a JSON array
+    /// of skin names.
+    pub fn search(&self, query: &str, favorites: &str) -> String {
+        skins_json(&self.results(query, &parse_favorites(favorites)))
+    }
+
+    /// Completions for the last word of `query`, best first.
+    pub fn suggest(&self, query: &str, favorites: &str) -> String {
+        let favorites = parse_favorites(favorites);
+        let results = self.results(query, &favorites);
+        let (suggestions, _) = suggest_terms(
+            query,
+            &self.skins,
+            &results,
+            &favorites,
+            &HashMap::new(),
+            &self.all_terms,
+        );
+        serde_json::to_string(&suggestions).unwrap_or_default()
+    }
+
+    /// How `query` scored the skin called `name`, as `{total, fields: [[field, points]]}`.
+    pub fn explain(&self, query: &str, name: &str, favorites: &str) -> String {
+        let Some(skin) = self.name_map.get(&name.to_lowercase()).map(|&i| &self.skins[i]) else {
+            return "null".to_string();
+        };
+        let breakdown =
+            explain_skin(skin, &query.to_lowercase(), &parse_favorites(favorites), &self.profile);
+        serde_json::json!({ "total": breakdown.total(), "fields": breakdown.fields() }).to_string()
+    }
+}
+
+impl SearchEngine {
+    fn results(&self, query: &str, favorites: &HashSet<String>) -> Vec<Skin> {
+        if query.trim().is_empty() {
+            return self.skins.clone();
+        }
+        search_skins(&self.skins, &self.name_map, &query.to_lowercase(), favorites, &self.profile)
+    }
+}
+
+fn parse_favorites(favorites: &str) -> HashSet<String> {
+    serde_json::from_str(favorites).unwrap_or_default()
+}
+
+fn skins_json(skins: &[Skin]) -> String {
+    let rows: Vec<serde_json::Value> = skins
+        .iter()
+        .map(|skin| {
+            serde_json::json!({
+                "name": skin.name,
+                "rarity": skin.rarity,
+                "event": skin.event,
+                "year": skin.year,
+                "tags": skin.tags,
+            })
+        })
+        .collect();
+    serde_json::to_string(&rows).unwrap_or_default()
+}
@@ -0,0 +1,136 @@
+use std::{fs, io, path::PathBuf};
+
+use crate::Skin;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Tsv,
+    Json,
+    Txt,
+}
+
+impl ExportFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Some(ExportFormat::Csv),
+            "tsv" => Some(ExportFormat::Tsv),
+            "json" => Some(ExportFormat::Json),
+            "txt" | "text" => Some(ExportFormat::Txt),
+            _ => None,
+        }
+    }
+}
+
+/// Expands a leading `~` to the home directory.
+fn expand_path(path: &str) -> PathBuf {
+    match path.strip_prefix('~') {
+        Some(rest) => {
+            let mut expanded = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+            expanded.push(rest.trim_start_matches('/'));
+            expanded
+        },
+        None => PathBuf::from(path),
+    }
+}
+
+pub fn render_skins(skins: &[Skin], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Csv => {
+            let mut out = String::from("name,rarity,event,year,tags\n");
+            for skin in skins {
+                let year = skin.year.map(|y| y.to_string()).unwrap_or_default();
+                let fields = [&skin.name, &skin.rarity, &skin.event, &year, &skin.tags.join(";")];
+                let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+                out.push_str(&row.join(","));
+                out.push('\n');
+            }
+            out
+        },
+        ExportFormat::Tsv => {
+            let mut out = String::from("name\trarity\tevent\tyear\ttags\n");
+            for skin in skins {
+                let year = skin.year.map(|y| y.to_string()).unwrap_or_default();
+                let fields = [&skin.name, &skin.rarity, &skin.event, &year, &skin.tags.join(", ")];
+                let row: Vec<String> =
+                    fields.iter().map(|f| f.replace(['\t', '\n'], " ")).collect();
+                out.push_str(&row.join("\t"));
+                out.push('\n');
+            }
+            out
+        },
+        ExportFormat::Json => {
+            let rows: Vec<serde_json::Value> = skins
+                .iter()
+                .map(|skin| {
+                    serde_json::json!({
+                        "name": skin.name,
+                        "rarity": skin.rarity,
+                        "event": skin.event,
+                        "year": skin.year,
+                        "tags": skin.tags,
+                    })
+                })
+                .collect();
+            serde_json::to_string_pretty(&rows).unwrap_or_default()
+        },
+        ExportFormat::Txt => skins
+            .iter()
+            .map(|skin| format!("{} | {} | {}\n", skin.name, skin.rarity, skin.event))
+            .collect(),
+    }
+}
+
+pub fn export_skins(skins: &[Skin], format: ExportFormat, path: &str) -> io::Result<PathBuf> {
+    let path = expand_path(path);
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, render_skins(skins, format))?;
+    Ok(path)
+}
+
+/// Quotes a CSV field when it contains a separator, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skin(name: &str, event: &str, tags: &[&str]) -> Skin {
+        Skin {
+            name: name.to_string(),
+            name_lower: name.to_lowercase(),
+            rarity: "Pink".to_string(),
+            rarity_lower: "pink".to_string(),
+            event: event.to_string(),
+            event_lower: event.to_lowercase(),
+            year: Some(2024),
+            year_str: "2024".to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            tags_lower: tags.iter().map(|tag| tag.to_lowercase()).collect(),
+        }
+    }
+
+    #[test]
+    fn csv_quotes_only_fields_that_need_it() {
+        let skins = [
+            skin("Cupid's Revenge", "Valentine's Case", &["popular", "case"]),
+            skin("Love, Actually", "Valentine's Case", &[]),
+            skin("The \"Heartbreaker\"", "Valentine's Case", &[]),
+        ];
+        assert_eq!(
+            render_skins(&skins, ExportFormat::Csv),
+            "name,rarity,event,year,tags\n\
+             Cupid's Revenge,Pink,Valentine's Case,2024,popular;case\n\
+             \"Love, Actually\",Pink,Valentine's Case,2024,\n\
+             \"The \"\"Heartbreaker\"\"\",Pink,Valentine's Case,2024,\n"
+        );
+    }
+}
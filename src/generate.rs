@@ -0,0 +1,202 @@
+use std::{collections::HashMap, fs, io};
+
+use crate::{load_skins, rarity_tier, Skin};
+
+pub const MAIN_JS: &str = "main.js";
+const SKIN_MD: &str = ".github/SKIN.md";
+pub const JS_START: &str = "const SKIN_COLLECTION = [";
+const JS_END: &str = "]";
+/// Opens a generated table, followed by its comma-separated events (or `*` for all of them) and
+/// `MD_START_CLOSE`, e.g. `<!-- BEGIN GENERATED SKIN TABLE: Code, Launch -->`.
+const MD_START: &str = "<!-- BEGIN GENERATED SKIN TABLE: ";
+const MD_START_CLOSE: &str = " -->";
+const MD_END: &str = "<!-- END GENERATED SKIN TABLE -->";
+const ALL_EVENTS: &str = "*";
+
+/// `pola generate [--check]`: rewrites the website's `SKIN_COLLECTION` and every skin table in
+/// `SKIN.md` from `load_skins`. With `--check` nothing is written and stale files are reported
+/// instead. Returns whether everything was already up to date or has been written.
+pub fn run(args: &[String]) -> io::Result<bool> {
+    let check = args.iter().any(|arg| arg == "--check");
+    let skins = load_skins();
+
+    let mut up_to_date = true;
+    for path in [MAIN_JS, SKIN_MD] {
+        let current = fs::read_to_string(path)?;
+        let generated = if path == MAIN_JS {
+            replace_js_collection(&current, &skins)
+                .ok_or_else(|| format!("no {} in {}", JS_START, path))
+        } else {
+            replace_md_tables(&current, &skins)
+                .map_err(|message| format!("{} in {}", message, path))
+        }
+        .map_err(|message| io::Error::new(io::ErrorKind::InvalidData, message))?;
+
+        if generated == current {
+            println!("{} is up to date", path);
+        } else if check {
+            println!("{} is stale, run `pola generate`", path);
+            up_to_date = false;
+        } else {
+            fs::write(path, generated)?;
+            println!("Updated {}", path);
+        }
+    }
+    Ok(up_to_date)
+}
+
+/// Swaps the `SKIN_COLLECTION` array for one built from `skins`, keeping each skin's `img`,
+/// which only the website knows about.
+fn replace_js_collection(js: &str, skins: &[Skin]) -> Option<String> {
+    let start = js.find(JS_START)?;
+    let end = start + js[start..].find(&format!("\n{}", JS_END))? + JS_END.len() + 1;
+    let images = existing_images(&js[start..end]);
+
+    let entries: Vec<String> = skins
+        .iter()
+        .map(|skin| {
+            let tags: Vec<String> = skin.tags.iter().map(|tag| js_string(tag)).collect();
+            let year = skin.year.map_or("null".to_string(), |y| y.to_string());
+            let img = images.get(&skin.name).cloned().unwrap_or_else(|| "null".to_string());
+            format!(
+                concat!(
+                    "  {{\n",
+                    "    name: {},\n",
+                    "    rarity: {},\n",
+                    "    event: {},\n",
+                    "    year: {},\n",
+                    "    tags: [{}],\n",
+                    "    img: {}\n",
+                    "  }}"
+                ),
+                js_string(&skin.name),
+                js_string(&skin.rarity),
+                js_string(&skin.event),
+                year,
+                tags.join(", "),
+                img
+            )
+        })
+        .collect();
+
+    Some(format!(
+        "{}{}\n{},\n{}{}",
+        &js[..start],
+        JS_START,
+        entries.join(",\n"),
+        JS_END,
+        &js[end..]
+    ))
+}
+
+/// Maps skin names to the raw `img:` values in an existing `SKIN_COLLECTION`.
+pub fn existing_images(collection: &str) -> HashMap<String, String> {
+    let mut images = HashMap::new();
+    let mut name = None;
+    for line in collection.lines().map(str::trim) {
+        if let Some(value) = line.strip_prefix("name:") {
+            name = serde_json::from_str::<String>(value.trim().trim_end_matches(',')).ok();
+        } else if let Some(value) = line.strip_prefix("img:") {
+            let value = value.trim().trim_end_matches(',');
+            if let Some(name) = name.take().filter(|_| value != "null") {
+                images.insert(name, value.to_string());
+            }
+        }
+    }
+    images
+}
+
+fn js_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Swaps the body of every generated-table marker pair for a table of the events it names.
+fn replace_md_tables(md: &str, skins: &[Skin]) -> Result<String, String> {
+    let mut out = String::new();
+    let mut rest = md;
+    while let Some(start) = rest.find(MD_START) {
+        let spec_start = start + MD_START.len();
+        let unclosed = || "an unclosed generated table".to_string();
+        let spec_end = spec_start + rest[spec_start..].find(MD_START_CLOSE).ok_or_else(unclosed)?;
+        let end = spec_end + rest[spec_end..].find(MD_END).ok_or_else(unclosed)? + MD_END.len();
+        let spec = &rest[spec_start..spec_end];
+        let events: Vec<&str> = if spec.trim() == ALL_EVENTS {
+            let mut events: Vec<&str> = skins.iter().map(|skin| skin.event.as_str()).collect();
+            events.sort();
+            events.dedup();
+            events
+        } else {
+            spec.split(',').map(str::trim).collect()
+        };
+        if let Some(event) =
+            events.iter().find(|event| !skins.iter().any(|skin| skin.event == **event))
+        {
+            return Err(format!("no skins for event {:?}", event));
+        }
+        out.push_str(&rest[..spec_end + MD_START_CLOSE.len()]);
+        out.push_str(&render_md_table(skins, &events));
+        out.push_str(MD_END);
+        rest = &rest[end..];
+    }
+    if out.is_empty() {
+        return Err("no generated tables".to_string());
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// A row per event in `events`, with a column per rarity any of them has.
+fn render_md_table(skins: &[Skin], events: &[&str]) -> String {
+    let skins: Vec<&Skin> =
+        skins.iter().filter(|skin| events.contains(&skin.event.as_str())).collect();
+    let mut rarities: Vec<&str> = skins.iter().map(|skin| skin.rarity.as_str()).collect();
+    rarities.sort_by_key(|rarity| (rarity_tier(&rarity.to_lowercase()), rarity.to_string()));
+    rarities.dedup();
+
+    let mut header = vec!["Event".to_string()];
+    header.extend(rarities.iter().map(|rarity| format!("{} Skins", rarity)));
+    let rows: Vec<Vec<String>> = events
+        .iter()
+        .map(|event| {
+            let mut row = vec![format!("<kbd>{}</kbd>", event)];
+            for rarity in &rarities {
+                let mut names: Vec<String> = skins
+                    .iter()
+                    .filter(|skin| skin.event == *event && skin.rarity == *rarity)
+                    .map(|skin| match skin.year {
+                        Some(year) => format!("{} ({})", skin.name, year),
+                        None => skin.name.clone(),
+                    })
+                    .collect();
+                names.sort();
+                row.push(if names.is_empty() { "-".to_string() } else { names.join(", ") });
+            }
+            row
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let format_row = |cells: &[String]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!(" {}{} ", cell, " ".repeat(width - cell.chars().count())))
+            .collect();
+        format!("|{}|\n", cells.join("|"))
+    };
+
+    let mut out = "\n\n".to_string();
+    out.push_str(&format_row(&header));
+    let rule: Vec<String> = widths.iter().map(|width| "-".repeat(width + 2)).collect();
+    out.push_str(&format!("|{}|\n", rule.join("|")));
+    for row in &rows {
+        out.push_str(&format_row(row));
+    }
+    out.push('\n');
+    out
+}
@@ -0,0 +1,29 @@
+//! pola's catalog, search engine and local API server as a library. The website runs the exact
+//! search the TUI does through `SearchEngine`; build it for the browser with
+//! `wasm-pack build --target web -- --features wasm`. `Server` is what `pola serve` runs, and
+//! `catalog` is the skin data every binary and the website are built from.
+
+// Shared with the binaries, which use more of these modules than the library does
+#[allow(dead_code)]
+mod aliases;
+pub mod catalog;
+mod config;
+#[allow(dead_code)]
+mod ranking;
+#[allow(dead_code)]
+mod search;
+#[allow(dead_code)]
+mod serve;
+#[allow(dead_code)]
+mod stats;
+#[allow(dead_code)]
+mod store;
+
+mod engine;
+
+use catalog::{load_skins, rarity_tier, Skin};
+
+pub use engine::SearchEngine;
+pub use ranking::RankingProfile;
+pub use serve::Server;
+pub use store::Store;
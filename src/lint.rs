@@ -0,0 +1,155 @@
+use std::{collections::HashMap, fs, io, path::Path};
+
+use serde_json::json;
+
+use crate::{
+    catalog::{catalog_entries, EVENTS, RARITIES},
+    generate::{existing_images, JS_START, MAIN_JS},
+    ranking::current_year,
+    Skin,
+};
+
+/// Years before this are typos rather than skins.
+const FIRST_YEAR: u32 = 2020;
+const EXQUISITE_SUFFIX: &str = " (Exquisite)";
+
+/// Something wrong with one skin, named by the check that found it.
+#[derive(Debug)]
+pub struct Problem {
+    pub skin: String,
+    pub check: &'static str,
+    pub message: String,
+}
+
+/// `pola lint-catalog`: checks the catalog and the website's images from the repository root,
+/// printing one JSON object per problem. Returns whether the catalog is clean.
+pub fn run(_args: &[String]) -> io::Result<bool> {
+    let js = fs::read_to_string(MAIN_JS)?;
+    let images = match js.find(JS_START) {
+        Some(start) => existing_images(&js[start..]),
+        None => HashMap::new(),
+    };
+    let skins = catalog_entries();
+    let problems = lint_catalog(&skins, &images, Path::new("."), current_year());
+    for problem in &problems {
+        println!(
+            "{}",
+            json!({ "skin": problem.skin, "check": problem.check, "message": problem.message })
+        );
+    }
+    let plural = if problems.len() == 1 { "" } else { "s" };
+    eprintln!("{} problem{} in {} skins", problems.len(), plural, skins.len());
+    Ok(problems.is_empty())
+}
+
+/// Checks `skins` as written in the catalog, so hand-typed lowercase fields are compared with
+/// what `load_skins` derives. `images` maps names to the raw `img:` values from `main.js`, and
+/// local image paths are looked up under `root`.
+pub fn lint_catalog(
+    skins: &[Skin],
+    images: &HashMap<String, String>,
+    root: &Path,
+    current_year: u32,
+) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut report = |skin: &str, check: &'static str, message: String| {
+        problems.push(Problem { skin: skin.to_string(), check, message });
+    };
+
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    for skin in skins {
+        let name = skin.name.as_str();
+        if name.trim().is_empty() || name.trim() != name {
+            report(name, "name", format!("name {:?} is empty or has surrounding spaces", name));
+        }
+        if let Some(first) = seen.insert(skin.name.to_lowercase(), name) {
+            report(name, "unique-name", format!("{:?} is already in the catalog", first));
+        }
+
+        if !RARITIES.contains(&skin.rarity.as_str()) {
+            report(
+                name,
+                "rarity",
+                format!(
+                    "unknown rarity {:?}, expected one of {}",
+                    skin.rarity,
+                    RARITIES.join(", ")
+                ),
+            );
+        }
+
+        let exquisite = skin.event.ends_with(EXQUISITE_SUFFIX);
+        let base_event = skin.event.strip_suffix(EXQUISITE_SUFFIX).unwrap_or(&skin.event);
+        if !EVENTS.contains(&base_event) || (exquisite && !base_event.ends_with(" Case")) {
+            report(name, "event", format!("unknown event {:?}", skin.event));
+        }
+        let has_exquisite_tag = skin.tags.iter().any(|tag| tag.eq_ignore_ascii_case("exquisite"));
+        if (exquisite || base_event == "Exquisite Case") != has_exquisite_tag {
+            report(
+                name,
+                "event",
+                format!(
+                    "event {:?} and the exquisite tag disagree; exquisite skins need both",
+                    skin.event
+                ),
+            );
+        }
+
+        let mut tags: Vec<&str> = Vec::new();
+        for tag in &skin.tags {
+            if tag.is_empty() || tag.contains(char::is_whitespace) {
+                report(name, "tag", format!("tag {:?} must be a single word", tag));
+            } else if tag.to_lowercase() != *tag {
+                report(name, "tag", format!("tag {:?} should be {:?}", tag, tag.to_lowercase()));
+            }
+            if tags.contains(&tag.as_str()) {
+                report(name, "tag", format!("tag {:?} is listed twice", tag));
+            }
+            tags.push(tag);
+        }
+
+        if let Some(year) = skin.year {
+            if !(FIRST_YEAR..=current_year + 1).contains(&year) {
+                report(
+                    name,
+                    "year",
+                    format!("year {} isn't between {} and {}", year, FIRST_YEAR, current_year + 1),
+                );
+            }
+        }
+
+        let derived = [
+            ("name_lower", &skin.name_lower, skin.name.to_lowercase()),
+            ("rarity_lower", &skin.rarity_lower, skin.rarity.to_lowercase()),
+            ("event_lower", &skin.event_lower, skin.event.to_lowercase()),
+            ("year_str", &skin.year_str, skin.year.map(|y| y.to_string()).unwrap_or_default()),
+        ];
+        for (field, written, expected) in derived {
+            if *written != expected {
+                report(
+                    name,
+                    "lowercase",
+                    format!("{} is {:?} but should be {:?}", field, written, expected),
+                );
+            }
+        }
+    }
+
+    let mut images: Vec<(&String, &String)> = images.iter().collect();
+    images.sort();
+    for (name, img) in images {
+        if !seen.contains_key(&name.to_lowercase()) {
+            report(name, "image", "main.js has an image for a skin not in the catalog".to_string());
+        }
+        let Ok(img) = serde_json::from_str::<String>(img) else {
+            report(name, "image", format!("img {} isn't a string", img));
+            continue;
+        };
+        let remote = img.starts_with("https://") || img.starts_with("http://");
+        if !remote && !root.join(&img).is_file() {
+            report(name, "image", format!("image {:?} doesn't exist", img));
+        }
+    }
+
+    problems
+}
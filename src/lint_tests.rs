@@ -0,0 +1,133 @@
+//! `lint_catalog` against the real catalog, which has to stay clean, and against small
+//! catalogs that each break one check.
+
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::{
+    catalog::catalog_entries,
+    generate::{existing_images, JS_START, MAIN_JS},
+    lint::lint_catalog,
+    Skin,
+};
+
+const YEAR: u32 = 2025;
+
+/// A skin whose lowercase fields agree with the rest, like `load_skins` would derive them.
+fn skin(name: &str, rarity: &str, event: &str, year: Option<u32>, tags: &[&str]) -> Skin {
+    Skin {
+        name: name.to_string(),
+        name_lower: name.to_lowercase(),
+        rarity: rarity.to_string(),
+        rarity_lower: rarity.to_lowercase(),
+        event: event.to_string(),
+        event_lower: event.to_lowercase(),
+        year,
+        year_str: year.map(|y| y.to_string()).unwrap_or_default(),
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        tags_lower: tags.iter().map(|tag| tag.to_lowercase()).collect(),
+    }
+}
+
+/// `(skin, check)` for every problem found in `skins`, with no images.
+fn lint(skins: &[Skin]) -> Vec<(String, &'static str)> {
+    lint_catalog(skins, &HashMap::new(), Path::new("."), YEAR)
+        .into_iter()
+        .map(|problem| (problem.skin, problem.check))
+        .collect()
+}
+
+fn found(skin: &str, check: &'static str) -> Vec<(String, &'static str)> {
+    vec![(skin.to_string(), check)]
+}
+
+#[test]
+fn the_catalog_is_clean() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let js = fs::read_to_string(root.join(MAIN_JS)).unwrap();
+    let images = existing_images(&js[js.find(JS_START).unwrap()..]);
+    let problems = lint_catalog(&catalog_entries(), &images, root, YEAR);
+    assert!(problems.is_empty(), "{:#?}", problems);
+}
+
+#[test]
+fn a_valid_skin_has_no_problems() {
+    assert!(lint(&[skin("Void Lord", "Teal", "Future Case", Some(2024), &["space"])]).is_empty());
+}
+
+#[test]
+fn duplicate_names() {
+    let skins = [
+        skin("Void Lord", "Teal", "Future Case", None, &[]),
+        skin("VOID LORD", "Pink", "Future Case", None, &[]),
+    ];
+    assert_eq!(lint(&skins), found("VOID LORD", "unique-name"));
+    assert_eq!(lint(&[skin(" Void", "Teal", "Future Case", None, &[])]), found(" Void", "name"));
+}
+
+#[test]
+fn unknown_rarity_or_event() {
+    assert_eq!(lint(&[skin("Ghost", "Gold", "Future Case", None, &[])]), found("Ghost", "rarity"));
+    assert_eq!(lint(&[skin("Ghost", "Red", "Moon Case", None, &[])]), found("Ghost", "event"));
+    // Only cases come in exquisite versions
+    let skins = [skin("Ghost", "Red", "Artist (Exquisite)", None, &["exquisite"])];
+    assert_eq!(lint(&skins), found("Ghost", "event"));
+}
+
+#[test]
+fn exquisite_event_and_tag_have_to_agree() {
+    let tagged = skin("Ghost", "Red", "Future Case (Exquisite)", None, &["exquisite"]);
+    assert!(lint(&[tagged]).is_empty());
+    let untagged = skin("Ghost", "Red", "Future Case (Exquisite)", None, &[]);
+    assert_eq!(lint(&[untagged]), found("Ghost", "event"));
+    let tag_only = skin("Ghost", "Red", "Future Case", None, &["exquisite"]);
+    assert_eq!(lint(&[tag_only]), found("Ghost", "event"));
+}
+
+#[test]
+fn tags_are_single_lowercase_words() {
+    for tags in [&["Space"][..], &["two words"], &[""], &["space", "space"]] {
+        let skins = [skin("Ghost", "Red", "Future Case", None, tags)];
+        assert_eq!(lint(&skins), found("Ghost", "tag"), "for {:?}", tags);
+    }
+}
+
+#[test]
+fn years_have_to_be_plausible() {
+    for year in [2020, YEAR + 1] {
+        assert!(lint(&[skin("Ghost", "Red", "Future Case", Some(year), &[])]).is_empty());
+    }
+    for year in [2019, YEAR + 2, 224] {
+        let skins = [skin("Ghost", "Red", "Future Case", Some(year), &[])];
+        assert_eq!(lint(&skins), found("Ghost", "year"), "for {}", year);
+    }
+}
+
+#[test]
+fn lowercase_fields_match_what_they_derive_from() {
+    let mut ghost = skin("Ghost", "Red", "Future Case", Some(2024), &[]);
+    ghost.event_lower = "future".to_string();
+    assert_eq!(lint(&[ghost]), found("Ghost", "lowercase"));
+}
+
+#[test]
+fn images_have_to_exist() {
+    let root = std::env::temp_dir().join(format!("pola-lint-{}", std::process::id()));
+    fs::create_dir_all(root.join("img")).unwrap();
+    fs::write(root.join("img/ghost.png"), "").unwrap();
+    let skins = [skin("Ghost", "Red", "Future Case", None, &[])];
+    let problems = |img: &str, name: &str| -> Vec<(String, &'static str)> {
+        let images = HashMap::from([(name.to_string(), img.to_string())]);
+        lint_catalog(&skins, &images, &root, YEAR)
+            .into_iter()
+            .map(|problem| (problem.skin, problem.check))
+            .collect()
+    };
+
+    assert!(problems("\"img/ghost.png\"", "Ghost").is_empty());
+    assert!(problems("\"https://example.com/ghost.png\"", "Ghost").is_empty());
+    assert_eq!(problems("\"img/missing.png\"", "Ghost"), found("Ghost", "image"));
+    assert_eq!(problems("null", "Ghost"), found("Ghost", "image"));
+    assert_eq!(problems("\"img/ghost.png\"", "Phantom"), found("Phantom", "image"));
+
+    fs::remove_dir_all(root).unwrap();
+}
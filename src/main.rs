@@ -13,19 +13,62 @@ use ratatui::{
     prelude::*,
     text::{Line, Span},
     widgets::{
-        Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Row, Scrollbar,
-        ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
+        block::Title, Bar, BarChart, BarGroup, Block, BorderType, Borders, Clear, List, ListItem,
+        ListState, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
+        TableState,
     },
     Terminal,
 };
-use regex::Regex;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
     fs, io,
-    path::PathBuf,
-    time::Duration,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
 };
 
+mod aliases;
+mod catalog;
+mod chat;
+mod clipboard;
+mod config;
+mod export;
+mod generate;
+mod lint;
+mod random;
+mod ranking;
+mod script;
+mod search;
+mod serve;
+mod settings;
+mod stats;
+mod store;
+mod typo;
+
+#[cfg(test)]
+mod lint_tests;
+#[cfg(test)]
+mod tui_tests;
+
+use aliases::{builtin_aliases, expand_aliases, load_aliases};
+use catalog::{load_skins, rarity_tier, Skin, EVENTS};
+use chat::{render_chat, ChatFormat, CHAT_LIMIT};
+use clipboard::copy_to_clipboard;
+use config::config_file;
+use export::{export_skins, render_skins, ExportFormat};
+use random::{load_rarity_weights, random_skin, skin_of_the_day};
+use ranking::{
+    load_ranking_config, save_ranking_config, RankingConfig, RankingProfile, ScoreBreakdown,
+};
+use script::{default_macros_dir, Step};
+use search::{
+    explain_skin, filter_term, load_all_terms, matches_filter, parse_query, search_skins,
+    split_filter, split_query, suggest_terms, TermInfo,
+};
+use settings::{get_settings_path, load_settings, save_settings, Settings};
+use stats::SkinStats;
+use store::{apply_custom_tags, Store};
+use typo::correct_query;
+
 // Passion Fruit Colors (Main Colors from MonkeyType)
 const D_BACKGROUND: Color = Color::Rgb(131, 60, 94);
 const D_FOREGROUND: Color = Color::Rgb(244, 163, 180);
@@ -37,76 +80,271 @@ const D_RED: Color = Color::Rgb(224, 108, 117);
 const D_YELLOW: Color = Color::Rgb(244, 163, 180);
 const D_TEAL: Color = Color::Rgb(244, 163, 180);
 
-#[derive(PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SortField {
     Name,
     Rarity,
     Event,
+    Year,
+}
+
+impl SortField {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "name" => Some(SortField::Name),
+            "rarity" => Some(SortField::Rarity),
+            "event" => Some(SortField::Event),
+            "year" => Some(SortField::Year),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortField::Name => "name",
+            SortField::Rarity => "rarity",
+            SortField::Event => "event",
+            SortField::Year => "year",
+        }
+    }
+
+    fn compare(self, a: &Skin, b: &Skin) -> std::cmp::Ordering {
+        match self {
+            SortField::Name => a.name_lower.cmp(&b.name_lower),
+            SortField::Rarity => rarity_tier(&a.rarity_lower).cmp(&rarity_tier(&b.rarity_lower)),
+            SortField::Event => a.event_lower.cmp(&b.event_lower),
+            SortField::Year => a.year.cmp(&b.year),
+        }
+    }
+}
+
+/// One column of a multi-column sort; earlier keys take priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SortKey {
+    field: SortField,
+    descending: bool,
+}
+
+/// A key binding: a single key, or a sequence of keys pressed one after another (`g g`).
+type KeyChord = Vec<(KeyCode, KeyModifiers)>;
+
+/// Where input comes from: the terminal, or a queue of events for tests and scripts.
+enum EventSource {
+    Terminal,
+    Scripted(VecDeque<Event>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputMode {
+    /// Keys type into the search bar. Without vim mode the TUI is always in this mode.
+    Insert,
+    /// Plain keys navigate; bindings prefixed with `normal:` apply.
+    Normal,
 }
 
-#[derive(Debug, Clone)]
-struct Skin {
-    name: String,
-    name_lower: String,
-    rarity: String,
-    rarity_lower: String,
-    event: String,
-    event_lower: String,
-    year: Option<u32>,
-    year_str: String,
-    tags: Vec<String>,
-    tags_lower: HashSet<String>,
+enum KeyMatch {
+    Action(String),
+    /// The key starts a longer chord and is held until the chord completes or breaks.
+    Pending,
+    Unbound,
 }
 
+/// Where each clickable widget was drawn in the last `ui()` pass, so mouse clicks are routed
+/// by what is actually on screen.
 #[derive(Default)]
-struct TermInfo {
-    is_name: bool,
-    is_event: bool,
-    is_rarity: bool,
-    is_tag: bool,
-    is_year: bool,
+struct UiLayout {
+    search: Rect,
+    /// Inner area of the suggestion list, one suggestion per line from `suggestion_offset`.
+    suggestions: Rect,
+    suggestion_offset: usize,
+    /// Header cells of the results table and the field each one sorts by.
+    header: Vec<(Rect, Option<SortField>)>,
+    /// Inner area of the result rows, one row per line from the table's scroll offset.
+    rows: Rect,
+    row_offset: usize,
+    /// Every visible tag in the tags column, with the row it belongs to.
+    tags: Vec<(Rect, String)>,
+    prev_page: Rect,
+    next_page: Rect,
+    /// Rarity badge and tag chips in the detail panel, with the filter each one adds.
+    chips: Vec<(Rect, String)>,
+    /// Inner area of the facet sidebar, one facet row per line from `facet_offset`.
+    facets: Rect,
+    facet_offset: usize,
+    /// The area shared by the table and detail panel, and whether the panel sits below.
+    split: Rect,
+    stacked: bool,
+    /// The detail panel's inner edge, which can be dragged to resize the split.
+    divider: Rect,
 }
 
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+
+/// Palette commands that take arguments, as `(usage, description)`.
+const PALETTE_COMMANDS: &[(&str, &str)] = &[
+    ("sort <name|rarity|event|year> [asc|desc] ...", "Sort the results by one or more columns"),
+    ("export <csv|tsv|json|txt> <path>", "Export the current results to a file"),
+    (
+        "chat <discord|markdown> [all]",
+        "Copy the selected skin, or all results, for pasting into chat",
+    ),
+];
+
 struct AppState {
     input: String,
     skins: Vec<Skin>,
     results: Vec<Skin>,
     table_state: TableState,
     all_terms: HashMap<String, TermInfo>,
+    aliases: HashMap<String, String>,
+    correction: Option<String>,
     suggestion: Option<String>,
     suggestion_list: Vec<String>,
     suggestion_index: usize,
     name_map: HashMap<String, usize>,
     input_history: Vec<String>,
     history_index: usize,
-    scroll_offset: usize,
-    sort_field: SortField,
-    sort_descending: bool,
+    sort_keys: Vec<SortKey>,
     show_detail: bool,
+    explain: bool,
+    ranking: RankingConfig,
+    ranking_profile: RankingProfile,
     current_suggestion_terms: HashMap<String, TermInfo>,
     current_page: usize,
     items_per_page: usize,
     favorites: HashSet<String>,
-    key_bindings: HashMap<String, (KeyCode, KeyModifiers)>,
+    store: Store,
+    key_bindings: HashMap<String, KeyChord>,
+    pending_keys: KeyChord,
+    settings: Settings,
+    settings_path: PathBuf,
+    mode: InputMode,
     should_exit: bool,
+    /// Problems found while loading the key binding file.
+    key_warnings: Vec<String>,
     help_state: ListState,
+    help_message: Option<String>,
     editing_keybinds: bool,
     awaiting_key: Option<String>,
+    palette_input: String,
+    palette_state: ListState,
+    palette_error: Option<String>,
+    jump_input: String,
+    jump_state: ListState,
+    status_message: Option<String>,
+    /// A confirmation drawn over the status bar until `TOAST_DURATION` has passed.
+    toast: Option<(String, Instant)>,
+    events: EventSource,
+    layout: UiLayout,
+    /// The detail panel chip the keyboard focus ring is on.
+    chip_focus: Option<usize>,
+    show_facets: bool,
+    /// Every `(field, value)` the sidebar offers, grouped by field.
+    facets: Vec<(&'static str, String)>,
+    /// How many skins each facet would match next to the rest of the query.
+    facet_counts: Vec<usize>,
+    facet_state: ListState,
+    rarity_weights: HashMap<String, u64>,
+    /// Name of today's featured skin, shown in the search bar.
+    daily_skin: Option<String>,
+    dragging_divider: bool,
+    /// Where `macro:<name>` bindings read `<name>.txt` and `record_macro` saves to.
+    macros_dir: PathBuf,
+    /// Steps taken since `record_macro` was pressed, or `None` when not recording.
+    recording: Option<Vec<Step>>,
+    /// Set when frames go to an off-screen terminal, so copies don't write to stdout.
+    headless: bool,
+}
+
+/// Everything the TUI reads from the user's files at startup.
+struct UserConfig {
+    store: Store,
+    aliases: HashMap<String, String>,
+    ranking: RankingConfig,
+    settings: Settings,
+    settings_path: PathBuf,
+    key_bindings: HashMap<String, KeyChord>,
+    /// Problems found while reading `key_bindings.json`.
+    key_warnings: Vec<String>,
+    rarity_weights: HashMap<String, u64>,
+    macros_dir: PathBuf,
+}
+
+impl UserConfig {
+    fn load() -> Self {
+        let (key_bindings, key_warnings) = load_key_bindings().unwrap_or_else(|e| {
+            let defaults = default_key_bindings();
+            save_default_key_bindings(&defaults).unwrap_or(());
+            (defaults, vec![format!("Couldn't read key bindings ({}), using defaults", e)])
+        });
+        UserConfig {
+            store: Store::default(),
+            aliases: load_aliases(),
+            ranking: load_ranking_config(),
+            settings: load_settings(&get_settings_path()),
+            settings_path: get_settings_path(),
+            key_bindings,
+            key_warnings,
+            rarity_weights: load_rarity_weights(),
+            macros_dir: default_macros_dir(),
+        }
+    }
+
+    /// The built-in configuration, with favorites and custom tags kept in `dir` so nothing the
+    /// user saved is read or changed.
+    fn defaults(dir: &Path) -> Self {
+        UserConfig {
+            store: Store {
+                favorites_path: dir.join("favorites.txt"),
+                tags_path: dir.join("custom_tags.json"),
+            },
+            aliases: builtin_aliases(),
+            ranking: RankingConfig::default(),
+            settings: Settings::default(),
+            settings_path: dir.join("settings.json"),
+            key_bindings: default_key_bindings(),
+            key_warnings: Vec::new(),
+            rarity_weights: HashMap::new(),
+            macros_dir: dir.join("macros"),
+        }
+    }
 }
 
 impl AppState {
     fn new() -> Self {
-        let skins = load_skins();
+        Self::with_config(UserConfig::load())
+    }
+
+    fn with_config(config: UserConfig) -> Self {
+        let UserConfig {
+            store,
+            aliases,
+            ranking,
+            settings,
+            settings_path,
+            key_bindings,
+            key_warnings,
+            rarity_weights,
+            macros_dir,
+        } = config;
+        let mut skins = load_skins();
+        apply_custom_tags(&mut skins, &store.load_tags());
         let name_map: HashMap<_, _> =
             skins.iter().enumerate().map(|(i, s)| (s.name_lower.clone(), i)).collect();
-        let all_terms = load_all_terms(&skins);
+        let all_terms = load_all_terms(&skins, &aliases);
+        let facets = load_facets(&all_terms);
+        let daily_skin = skin_of_the_day(&skins).map(|skin| skin.name.clone());
         let mut results = skins.clone();
         results.sort_by(|a, b| a.name_lower.cmp(&b.name_lower));
-        let favorites = load_favorites().unwrap_or_default();
-        let key_bindings = load_key_bindings().unwrap_or_else(|_| {
-            let defaults = default_key_bindings();
-            save_default_key_bindings(&defaults).unwrap_or(());
-            defaults
+        let favorites = store.load_favorites();
+        let ranking_profile = ranking.active_profile();
+        let mode = if settings.vim_mode { InputMode::Normal } else { InputMode::Insert };
+        let conflicts = find_key_conflicts(&key_bindings);
+        let status_message = (!key_warnings.is_empty() || !conflicts.is_empty()).then(|| {
+            format!(
+                "{} key binding problem(s) found, see the help page",
+                key_warnings.len() + conflicts.len()
+            )
         });
 
         AppState {
@@ -115,25 +353,55 @@ impl AppState {
             results,
             table_state: TableState::default().with_selected(Some(0)),
             all_terms,
+            aliases,
+            correction: None,
             suggestion: None,
             suggestion_list: Vec::new(),
             suggestion_index: 0,
             name_map,
             input_history: vec![String::new()],
             history_index: 0,
-            scroll_offset: 0,
-            sort_field: SortField::Name,
-            sort_descending: false,
+            sort_keys: vec![SortKey { field: SortField::Name, descending: false }],
             show_detail: true,
+            explain: false,
+            ranking,
+            ranking_profile,
             current_suggestion_terms: HashMap::new(),
             current_page: 0,
             items_per_page: 10,
             favorites,
+            store,
             key_bindings,
+            pending_keys: Vec::new(),
+            settings,
+            settings_path,
+            mode,
             should_exit: false,
+            key_warnings,
             help_state: ListState::default().with_selected(Some(0)),
+            help_message: None,
             editing_keybinds: false,
             awaiting_key: None,
+            palette_input: String::new(),
+            palette_state: ListState::default().with_selected(Some(0)),
+            palette_error: None,
+            jump_input: String::new(),
+            jump_state: ListState::default().with_selected(Some(0)),
+            status_message,
+            toast: None,
+            events: EventSource::Terminal,
+            layout: UiLayout::default(),
+            chip_focus: None,
+            show_facets: false,
+            facets,
+            facet_counts: Vec::new(),
+            facet_state: ListState::default().with_selected(Some(1)),
+            rarity_weights,
+            daily_skin,
+            dragging_divider: false,
+            macros_dir,
+            recording: None,
+            headless: false,
         }
     }
 
@@ -141,10 +409,10 @@ impl AppState {
         let selected_index = self.table_state.selected().unwrap_or(0);
         let current_page = self.current_page;
 
+        self.correction = None;
         if self.input.trim().is_empty() {
             self.results = self.skins.clone();
-            self.sort_field = SortField::Name;
-            self.sort_descending = false;
+            self.sort_keys = vec![SortKey { field: SortField::Name, descending: false }];
             self.sort_results(); // Sort by name ascending when empty
 
             let total_pages = self.results.len().div_ceil(self.items_per_page);
@@ -164,8 +432,19 @@ impl AppState {
             self.suggestion_index = 0;
             self.suggestion = None;
         } else {
-            self.results = search_skins(&self.skins, &self.name_map, &self.input, &self.favorites);
-            if self.sort_field != SortField::Name || self.sort_descending {
+            // What was typed comes first; a correction only stands in when it finds something
+            let query = self.search_query();
+            self.results = self.search(&query);
+            if self.results.is_empty() {
+                if let Some(corrected) = self.correct_typos(&query) {
+                    let results = self.search(&corrected);
+                    if !results.is_empty() {
+                        self.results = results;
+                        self.correction = Some(corrected);
+                    }
+                }
+            }
+            if !self.is_default_sort() {
                 self.sort_results();
             }
 
@@ -183,158 +462,472 @@ impl AppState {
             self.table_state.select(new_selection);
             self.update_suggestion();
         }
+        if self.show_facets {
+            self.update_facet_counts();
+        }
     }
 
-    fn toggle_favorite(&mut self) {
-        if let Some(selected) = self.table_state.selected() {
-            let absolute_index = self.current_page * self.items_per_page + selected;
-            if let Some(skin) = self.results.get(absolute_index) {
-                if self.favorites.contains(&skin.name) {
-                    self.favorites.remove(&skin.name);
+    /// Counts each facet against the query minus its group's filter, since facets in one group
+    /// are alternatives (Pink or Red) rather than narrowing each other.
+    fn update_facet_counts(&mut self) {
+        let query = self.search_query();
+        let mut group_results: HashMap<&str, Vec<Skin>> = HashMap::new();
+        for (field, _) in &self.facets {
+            group_results.entry(field).or_insert_with(|| {
+                let parts = split_query(&query);
+                let group = facet_filter(&parts, field);
+                let rest: Vec<&str> = parts
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| Some(i) != group)
+                    .map(|(_, part)| *part)
+                    .collect();
+                if rest.is_empty() {
+                    self.skins.clone()
                 } else {
-                    self.favorites.insert(skin.name.clone());
+                    search_skins(
+                        &self.skins,
+                        &self.name_map,
+                        &rest.join(" "),
+                        &self.favorites,
+                        &self.ranking_profile,
+                    )
                 }
-                save_favorites(&self.favorites).expect("Failed to save favorites");
-                self.update_search();
+            });
+        }
+        self.facet_counts = self
+            .facets
+            .iter()
+            .map(|(field, value)| {
+                group_results[field]
+                    .iter()
+                    .filter(|skin| {
+                        matches_filter(skin, field, value, &self.favorites) == Some(true)
+                    })
+                    .count()
+            })
+            .collect();
+    }
+
+    /// Sidebar rows: a group heading (`None`) before each field's facets (`Some(index)`).
+    fn facet_rows(&self) -> Vec<Option<usize>> {
+        let mut rows = Vec::new();
+        for (i, (field, _)) in self.facets.iter().enumerate() {
+            if i == 0 || self.facets[i - 1].0 != *field {
+                rows.push(None);
             }
+            rows.push(Some(i));
         }
+        rows
     }
 
-    fn sort_results(&mut self) {
-        match self.sort_field {
-            SortField::Name => {
-                if self.sort_descending {
-                    self.results.sort_by(|a, b| b.name_lower.cmp(&a.name_lower));
-                } else {
-                    self.results.sort_by(|a, b| a.name_lower.cmp(&b.name_lower));
-                }
-            },
-            SortField::Rarity => {
-                if self.sort_descending {
-                    self.results.sort_by(|a, b| b.rarity_lower.cmp(&a.rarity_lower));
-                } else {
-                    self.results.sort_by(|a, b| a.rarity_lower.cmp(&b.rarity_lower));
-                }
-            },
-            SortField::Event => {
-                if self.sort_descending {
-                    self.results.sort_by(|a, b| b.event_lower.cmp(&a.event_lower));
-                } else {
-                    self.results.sort_by(|a, b| a.event_lower.cmp(&b.event_lower));
-                }
-            },
+    fn is_facet_checked(&self, index: usize) -> bool {
+        let (field, value) = &self.facets[index];
+        parse_query(&self.search_query())
+            .filters
+            .iter()
+            .any(|(filter, values)| filter == field && values.contains(value))
+    }
+
+    /// Checks or unchecks a facet by editing its group's filter in the search input, which lists
+    /// every checked facet of the group as alternatives, e.g. `rarity:pink,red`.
+    fn toggle_facet(&mut self, index: usize) {
+        let (field, value) = self.facets[index].clone();
+        let checked = self.is_facet_checked(index);
+        let mut parts: Vec<String> =
+            split_query(&self.input).into_iter().map(String::from).collect();
+        let group = facet_filter(&parts, field);
+        if !checked && group.is_none() {
+            self.add_query_term(&filter_term(field, &[&value]));
+            return;
+        }
+        for (i, part) in parts.iter_mut().enumerate() {
+            let Some((filter, mut values)) = split_filter(part).filter(|(f, _)| f == field) else {
+                continue;
+            };
+            if checked {
+                values.retain(|v| *v != value);
+            } else if Some(i) == group {
+                values.push(value.clone());
+            } else {
+                continue;
+            }
+            let values: Vec<&str> = values.iter().map(String::as_str).collect();
+            *part = if values.is_empty() { String::new() } else { filter_term(&filter, &values) };
         }
+        let rest: Vec<String> = parts.into_iter().filter(|part| !part.is_empty()).collect();
+        self.input = if rest.is_empty() { String::new() } else { format!("{} ", rest.join(" ")) };
+        self.update_search();
+        self.record_input();
     }
 
-    fn toggle_sort(&mut self, field: SortField) {
-        if self.sort_field == field {
-            self.sort_descending = !self.sort_descending;
-        } else {
-            self.sort_field = field;
-            self.sort_descending = true;
+    /// Moves the sidebar cursor by one facet, skipping group headings.
+    fn move_facet_cursor(&mut self, direction: i32) {
+        let rows = self.facet_rows();
+        let mut row = self.facet_state.selected().unwrap_or(0) as i32;
+        loop {
+            row += direction;
+            match rows.get(row as usize) {
+                _ if row < 0 => return,
+                Some(Some(_)) => break,
+                Some(None) => continue,
+                None => return,
+            }
         }
-        self.sort_results();
+        self.facet_state.select(Some(row as usize));
     }
 
-    fn update_suggestion(&mut self) {
-        let input_parts: Vec<&str> = self.input.split_whitespace().collect();
-        let last_part = input_parts.last().cloned().unwrap_or("");
-        let last_part_lower = last_part.to_lowercase();
-        self.suggestion_list.clear();
-        self.suggestion_index = 0;
+    /// Moves the table to the page and row of `name`, if it's among the results.
+    fn select_skin(&mut self, name: &str) -> bool {
+        let Some(index) = self.results.iter().position(|skin| skin.name == name) else {
+            return false;
+        };
+        self.current_page = index / self.items_per_page;
+        self.table_state.select(Some(index % self.items_per_page));
+        true
+    }
 
-        if !last_part_lower.is_empty() {
-            let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
-            let mut current_terms = HashMap::new();
+    /// Jumps to a random skin from the current results, weighted by rarity.
+    fn pick_random_skin(&mut self) {
+        let Some(name) = random_skin(&self.results, &self.rarity_weights).map(|s| s.name.clone())
+        else {
+            self.status_message = Some("No skins to pick from".to_string());
+            return;
+        };
+        self.select_skin(&name);
+        self.status_message = Some(format!("Random pick: {}", name));
+    }
 
-            if "favorite".contains(&last_part_lower) && !self.favorites.is_empty() {
-                current_terms
-                    .entry("favorite".to_string())
-                    .or_insert_with(|| TermInfo { is_tag: true, ..TermInfo::default() });
-            }
+    /// Jumps to the skin of the day, clearing the query if it filters that skin out.
+    fn show_daily_skin(&mut self) {
+        let Some(name) = self.daily_skin.clone() else {
+            return;
+        };
+        if !self.select_skin(&name) {
+            self.input.clear();
+            self.record_input();
+            self.update_search();
+            self.select_skin(&name);
+        }
+        self.status_message = Some(format!("Skin of the day: {}", name));
+    }
 
-            for skin in &self.skins {
-                if !skin.year_str.is_empty() && skin.year_str.contains(&last_part_lower) {
-                    current_terms
-                        .entry(skin.year_str.clone())
-                        .or_insert_with(|| TermInfo { is_year: true, ..TermInfo::default() });
-                }
-                for tag in &skin.tags_lower {
-                    if tag.contains(&last_part_lower) {
-                        current_terms
-                            .entry(tag.clone())
-                            .or_insert_with(|| TermInfo { is_tag: true, ..TermInfo::default() });
-                    }
-                }
-            }
+    fn toggle_facets(&mut self) {
+        self.show_facets = !self.show_facets;
+        if self.show_facets {
+            self.update_facet_counts();
+        }
+    }
 
-            for skin in &self.results {
-                if skin.rarity_lower.contains(&last_part_lower) {
-                    current_terms
-                        .entry(skin.rarity_lower.clone())
-                        .or_insert_with(|| TermInfo { is_rarity: true, ..TermInfo::default() });
-                }
-                for word in skin.name_lower.split_whitespace() {
-                    if word.contains(&last_part_lower) {
-                        current_terms
-                            .entry(word.to_string())
-                            .or_insert_with(|| TermInfo { is_name: true, ..TermInfo::default() });
-                    }
-                }
-                if skin.event_lower.contains(&last_part_lower) {
-                    current_terms
-                        .entry(skin.event_lower.clone())
-                        .or_insert_with(|| TermInfo { is_event: true, ..TermInfo::default() });
-                }
+    /// Appends `term` to the search input as if it had been typed.
+    fn add_query_term(&mut self, term: &str) {
+        if split_query(&self.input).iter().any(|part| part.eq_ignore_ascii_case(term)) {
+            return;
+        }
+        let input = self.input.trim_end();
+        self.input =
+            if input.is_empty() { format!("{} ", term) } else { format!("{} {} ", input, term) };
+        self.update_search();
+        self.record_input();
+    }
+
+    fn handle_click(&mut self, column: u16, row: u16, modifiers: KeyModifiers) {
+        let hit = |rect: &Rect| {
+            rect.x <= column && column < rect.right() && rect.y <= row && row < rect.bottom()
+        };
+
+        if hit(&self.layout.divider) {
+            self.dragging_divider = true;
+        } else if let Some((_, field)) = self.layout.header.iter().find(|(rect, _)| hit(rect)) {
+            // Shift/Ctrl/Alt-click adds the column as a secondary sort key
+            match *field {
+                Some(field) if !modifiers.is_empty() => self.add_sort_key(field),
+                Some(field) => self.toggle_sort(field),
+                None => {},
+            }
+        } else if let Some(i) = self.layout.chips.iter().position(|(rect, _)| hit(rect)) {
+            self.chip_focus = Some(i);
+            self.activate_chip(!modifiers.is_empty());
+        } else if let Some((_, tag)) = self.layout.tags.iter().find(|(rect, _)| hit(rect)) {
+            let term = filter_term("tag", &[&tag.to_lowercase()]);
+            self.add_query_term(&term);
+        } else if hit(&self.layout.facets) {
+            let row = self.layout.facet_offset + (row - self.layout.facets.y) as usize;
+            if let Some(Some(index)) = self.facet_rows().get(row) {
+                self.facet_state.select(Some(row));
+                self.toggle_facet(*index);
+            }
+        } else if hit(&self.layout.rows) {
+            let index = self.layout.row_offset + (row - self.layout.rows.y) as usize;
+            let start = self.current_page * self.items_per_page;
+            if start + index < self.results.len() {
+                self.table_state.select(Some(index));
+            }
+        } else if hit(&self.layout.suggestions) {
+            let index = self.layout.suggestion_offset + (row - self.layout.suggestions.y) as usize;
+            if let Some(suggestion) = self.suggestion_list.get(index) {
+                self.suggestion_index = index;
+                self.suggestion = Some(suggestion.clone());
+                self.accept_suggestion();
             }
+        } else if hit(&self.layout.prev_page) {
+            self.handle_action("page_up");
+        } else if hit(&self.layout.next_page) {
+            self.handle_action("page_down");
+        } else if hit(&self.layout.search) && self.settings.vim_mode {
+            self.mode = InputMode::Insert;
+        }
+    }
 
-            let used_terms: HashSet<_> = input_parts[..input_parts.len().saturating_sub(1)]
-                .iter()
-                .map(|s| s.to_lowercase())
-                .collect();
+    /// Follows the mouse while the table/detail divider is being dragged.
+    fn drag_divider(&mut self, column: u16, row: u16) {
+        let split = self.layout.split;
+        let (position, start, length) = if self.layout.stacked {
+            (row, split.y, split.height)
+        } else {
+            (column, split.x, split.width)
+        };
+        if !self.dragging_divider || length == 0 {
+            return;
+        }
+        let percent = position.saturating_sub(start) as u32 * 100 / length as u32;
+        self.settings.table_percent = (percent as u16).clamp(20, 80);
+    }
 
-            let mut suggestions = Vec::new();
-            for (term, term_info) in current_terms.iter() {
-                if used_terms.contains(term) {
-                    continue;
-                }
+    fn release_divider(&mut self) {
+        if self.dragging_divider {
+            self.dragging_divider = false;
+            self.save_settings();
+        }
+    }
 
-                let score = matcher.fuzzy_match(term, &last_part_lower).unwrap_or(i64::MIN);
-                let mut boost = match true {
-                    _ if *term == last_part_lower => 10000,
-                    _ if *term == "favorite" => 6000,
-                    _ if term_info.is_rarity => 5000,
-                    _ if term_info.is_name => 4000,
-                    _ if term_info.is_event => 3000,
-                    _ if term_info.is_year => 2500,
-                    _ if term_info.is_tag => 2000,
-                    _ => 0,
-                };
+    fn resize_table(&mut self, delta: i16) {
+        let percent = self.settings.table_percent as i16 + delta;
+        self.settings.table_percent = percent.clamp(20, 80) as u16;
+        self.save_settings();
+    }
 
-                if term.starts_with(&last_part_lower) {
-                    boost += 1500;
-                }
+    fn toggle_suggestions(&mut self) {
+        self.settings.show_suggestions = !self.settings.show_suggestions;
+        self.save_settings();
+    }
+
+    /// Saves the settings, reporting a failure in the status bar rather than quitting over it.
+    fn save_settings(&mut self) {
+        if let Err(e) = save_settings(&self.settings, &self.settings_path) {
+            self.status_message = Some(format!("Couldn't save settings: {}", e));
+        }
+    }
+
+    /// Changes the page size to fit the table, keeping the same skin selected.
+    fn set_items_per_page(&mut self, items_per_page: usize) {
+        let items_per_page = items_per_page.max(1);
+        if items_per_page == self.items_per_page {
+            return;
+        }
+        let absolute_index =
+            self.current_page * self.items_per_page + self.table_state.selected().unwrap_or(0);
+        self.items_per_page = items_per_page;
+        self.current_page = absolute_index / items_per_page;
+        self.table_state.select(Some(absolute_index % items_per_page));
+    }
+
+    fn selected_skin(&self) -> Option<&Skin> {
+        let absolute_index =
+            self.current_page * self.items_per_page + self.table_state.selected().unwrap_or(0);
+        self.results.get(absolute_index)
+    }
+
+    /// The filters the detail panel offers for the selected skin: its rarity, then its tags.
+    fn detail_chips(&self) -> Vec<String> {
+        let Some(skin) = self.selected_skin() else {
+            return Vec::new();
+        };
+        let mut chips = vec![filter_term("rarity", &[&skin.rarity_lower])];
+        chips.extend(skin.tags.iter().map(|tag| filter_term("tag", &[&tag.to_lowercase()])));
+        if self.favorites.contains(&skin.name) {
+            chips.push("tag:favorite".to_string());
+        }
+        chips
+    }
+
+    /// Moves the focus ring to the next chip, leaving the panel after the last one.
+    fn focus_next_chip(&mut self) {
+        let count = self.detail_chips().len();
+        self.chip_focus = match self.chip_focus {
+            None if count > 0 => Some(0),
+            Some(i) if i + 1 < count => Some(i + 1),
+            _ => None,
+        };
+    }
+
+    /// Adds the focused chip's filter to the query, or its negated form when `exclude` is set.
+    fn activate_chip(&mut self, exclude: bool) {
+        let chips = self.detail_chips();
+        if let Some(chip) = self.chip_focus.and_then(|i| chips.get(i)) {
+            let term = if exclude { format!("-{}", chip) } else { chip.clone() };
+            self.chip_focus = None;
+            self.add_query_term(&term);
+        }
+    }
+
+    /// Puts `text` on the clipboard and shows a toast saying `what` was copied.
+    fn copy(&mut self, text: &str, what: &str) -> Result<String, String> {
+        // The clipboard escape sequence would end up in a script's printed output
+        if !self.headless {
+            copy_to_clipboard(text).map_err(|e| format!("Copy failed: {}", e))?;
+        }
+        let message = format!("Copied {}", what);
+        self.toast = Some((message.clone(), Instant::now()));
+        Ok(message)
+    }
 
-                let length_penalty = (term.len() as i64).saturating_sub(4) * 100;
-                let total_score = score + boost - length_penalty;
+    /// Copies `skins` to the clipboard as chat text, returning a message for the status bar.
+    fn copy_for_chat(&mut self, skins: &[Skin], format: ChatFormat) -> Result<String, String> {
+        let what = match skins {
+            [skin] => format!("{} for chat", skin.name),
+            _ => format!("{} skins for chat", skins.len()),
+        };
+        self.copy(&render_chat(skins, format, CHAT_LIMIT), &what)
+    }
+
+    /// Runs one of the copy actions, reporting failures in the status bar.
+    fn copy_action(&mut self, action: &str) {
+        let result = match (action, self.selected_skin().cloned()) {
+            ("copy_query", _) => match self.input.trim().to_string() {
+                query if query.is_empty() => Err("The search is empty".to_string()),
+                query => self.copy(&query, &format!("\"{}\"", query)),
+            },
+            ("copy_rows", _) if self.results.is_empty() => Err("No results to copy".to_string()),
+            ("copy_rows", _) => {
+                // The rows on screen, not every result
+                let start = (self.current_page * self.items_per_page).min(self.results.len());
+                let end = (start + self.items_per_page).min(self.results.len());
+                let rows = render_skins(&self.results[start..end], ExportFormat::Tsv);
+                let what = format!("page {} ({} rows) as TSV", self.current_page + 1, end - start);
+                self.copy(&rows, &what)
+            },
+            (_, None) => Err("No skin selected".to_string()),
+            ("copy_name", Some(skin)) => self.copy(&skin.name, &skin.name),
+            (_, Some(skin)) => self.copy_for_chat(&[skin], ChatFormat::Discord),
+        };
+        if let Err(error) = result {
+            self.status_message = Some(error);
+        }
+    }
 
-                if total_score > 0 {
-                    suggestions.push((total_score, term.clone()));
+    fn toggle_favorite(&mut self) {
+        if let Some(selected) = self.table_state.selected() {
+            let absolute_index = self.current_page * self.items_per_page + selected;
+            if let Some(skin) = self.results.get(absolute_index) {
+                if self.favorites.contains(&skin.name) {
+                    self.favorites.remove(&skin.name);
+                } else {
+                    self.favorites.insert(skin.name.clone());
                 }
+                self.store.save_favorites(&self.favorites).expect("Failed to save favorites");
+                self.update_search();
             }
+        }
+    }
+
+    fn search(&self, query: &str) -> Vec<Skin> {
+        search_skins(&self.skins, &self.name_map, query, &self.favorites, &self.ranking_profile)
+    }
+
+    /// The query the search engine runs: the input with aliases expanded, or its typo
+    /// correction when only that has results.
+    fn search_query(&self) -> String {
+        self.correction.clone().unwrap_or_else(|| expand_aliases(&self.input, &self.aliases))
+    }
+
+    /// Corrects terms that match no skin to the closest word in the term index.
+    fn correct_typos(&self, query: &str) -> Option<String> {
+        let vocabulary: Vec<&str> = self.all_terms.keys().map(String::as_str).collect();
+        let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+        correct_query(query, &vocabulary, |term| {
+            self.skins.iter().any(|skin| {
+                let is_favorite = self.favorites.contains(&skin.name);
+                self.ranking_profile.score(skin, [term], is_favorite, &matcher).is_some()
+            })
+        })
+    }
+
+    fn cycle_ranking_profile(&mut self) {
+        if self.ranking.profiles.is_empty() {
+            return;
+        }
+        let current =
+            self.ranking.profiles.iter().position(|p| p.name == self.ranking_profile.name);
+        let next = current.map_or(0, |i| (i + 1) % self.ranking.profiles.len());
+        self.ranking_profile = self.ranking.profiles[next].clone();
+        self.ranking.active = self.ranking_profile.name.clone();
+        save_ranking_config(&self.ranking).expect("Failed to save ranking profile");
+        self.update_search();
+    }
+
+    /// Name ascending, which with a query means the results keep their relevance order.
+    fn is_default_sort(&self) -> bool {
+        self.sort_keys == [SortKey { field: SortField::Name, descending: false }]
+    }
+
+    fn sort_results(&mut self) {
+        let keys = self.sort_keys.clone();
+        self.results.sort_by(|a, b| {
+            keys.iter().fold(std::cmp::Ordering::Equal, |ordering, key| {
+                ordering.then_with(|| {
+                    let ordering = key.field.compare(a, b);
+                    if key.descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                })
+            })
+        });
+    }
+
+    /// Sorts by `field` alone, flipping its direction if it already was the only sort key.
+    fn toggle_sort(&mut self, field: SortField) {
+        match self.sort_keys.as_slice() {
+            [key] if key.field == field => self.sort_keys[0].descending = !key.descending,
+            _ => self.sort_keys = vec![SortKey { field, descending: true }],
+        }
+        self.sort_results();
+    }
 
-            suggestions.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
-            let mut seen = HashSet::new();
-            self.suggestion_list = suggestions
-                .into_iter()
-                .filter(|(_, term)| seen.insert(term.clone()))
-                .take(5)
-                .map(|(_, term)| term)
-                .collect();
+    /// Adds `field` as the lowest-priority sort key. Clicking it again flips it to ascending,
+    /// and a third time removes it.
+    fn add_sort_key(&mut self, field: SortField) {
+        match self.sort_keys.iter().position(|key| key.field == field) {
+            Some(i) if self.sort_keys[i].descending => self.sort_keys[i].descending = false,
+            Some(i) if self.sort_keys.len() > 1 => {
+                self.sort_keys.remove(i);
+            },
+            Some(i) => self.sort_keys[i].descending = true,
+            None => self.sort_keys.push(SortKey { field, descending: true }),
+        }
+        self.sort_results();
+    }
 
-            self.current_suggestion_terms = current_terms;
-            self.suggestion = self.suggestion_list.first().cloned();
+    fn update_suggestion(&mut self) {
+        self.suggestion_list.clear();
+        self.suggestion_index = 0;
+        if self.input.split_whitespace().next().is_none() {
+            return;
         }
+
+        let (suggestions, current_terms) = suggest_terms(
+            &self.input,
+            &self.skins,
+            &self.results,
+            &self.favorites,
+            &self.aliases,
+            &self.all_terms,
+        );
+        self.suggestion_list = suggestions;
+        self.current_suggestion_terms = current_terms;
+        self.suggestion = self.suggestion_list.first().cloned();
     }
 
     fn cycle_suggestion(&mut self, direction: i32) {
@@ -437,13 +1030,172 @@ impl AppState {
         }
     }
 
-    fn get_action_for_key(&self, key: &KeyEvent) -> Option<String> {
-        for (action, &(bound_key, bound_modifiers)) in &self.key_bindings {
-            if key.code == bound_key && key.modifiers == bound_modifiers {
-                return Some(action.clone());
+    /// Feeds `key` into the pending chord and resolves it against the bindings of the current
+    /// mode. Mode-prefixed bindings (`normal:`, `insert:`) win over global ones.
+    fn get_action_for_key(&mut self, key: &KeyEvent) -> KeyMatch {
+        self.pending_keys.push((key.code, key.modifiers));
+        let prefix = match self.mode {
+            _ if !self.settings.vim_mode => None,
+            InputMode::Normal => Some("normal:"),
+            InputMode::Insert => Some("insert:"),
+        };
+        // (action, chord, whether the binding belongs to the current mode)
+        let active: Vec<(&str, &KeyChord, bool)> = self
+            .key_bindings
+            .iter()
+            .filter_map(|(action, chord)| match action.split_once(':') {
+                Some(("macro", _)) => Some((action.as_str(), chord, false)),
+                Some((_, name)) if prefix.is_some_and(|p| action.starts_with(p)) => {
+                    Some((name, chord, true))
+                },
+                Some(_) => None,
+                None => Some((action.as_str(), chord, false)),
+            })
+            .collect();
+
+        let exact = active
+            .iter()
+            .filter(|(_, chord, _)| chords_match(chord, &self.pending_keys))
+            .max_by_key(|(_, _, mode_specific)| *mode_specific)
+            .map(|(name, _, _)| name.to_string());
+        if let Some(action) = exact {
+            self.pending_keys.clear();
+            return KeyMatch::Action(action);
+        }
+        if active.iter().any(|(_, chord, _)| {
+            chord.len() > self.pending_keys.len()
+                && chords_match(&chord[..self.pending_keys.len()], &self.pending_keys)
+        }) {
+            return KeyMatch::Pending;
+        }
+
+        // A broken chord drops the keys before it; the last key may still start a binding
+        let retry = self.pending_keys.len() > 1;
+        self.pending_keys.clear();
+        if retry {
+            self.get_action_for_key(key)
+        } else {
+            KeyMatch::Unbound
+        }
+    }
+
+    /// Restores the default binding of `action`, refusing if another action has taken that key.
+    fn reset_key_binding(&mut self, action: &str) -> io::Result<()> {
+        let Some(default) = default_key_bindings().remove(action) else {
+            return Ok(());
+        };
+        if let Some(other) = binding_conflict(&self.key_bindings, action, &default) {
+            self.help_message =
+                Some(format!("Default {} is now bound to {}", get_key_chord_str(&default), other));
+            return Ok(());
+        }
+        self.key_bindings.insert(action.to_string(), default);
+        save_default_key_bindings(&self.key_bindings)
+    }
+
+    fn toggle_vim_mode(&mut self) {
+        self.settings.vim_mode = !self.settings.vim_mode;
+        self.mode = if self.settings.vim_mode { InputMode::Normal } else { InputMode::Insert };
+        self.pending_keys.clear();
+        self.save_settings();
+    }
+
+    /// The next input event, or `None` if nothing arrived within a frame. Scripted input fails
+    /// once it runs out, since a dialog waiting for more would otherwise never close.
+    fn poll_event(&mut self) -> io::Result<Option<Event>> {
+        let event = match &mut self.events {
+            EventSource::Terminal => {
+                if event::poll(Duration::from_millis(50))? {
+                    event::read().map(Some)
+                } else {
+                    Ok(None)
+                }
+            },
+            EventSource::Scripted(events) => events.pop_front().map(Some).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::UnexpectedEof, "scripted input ran out")
+            }),
+        }?;
+        // Dialogs read their keys here too, so a recording includes what was typed into them
+        if let (Some(steps), Some(Event::Key(key))) = (&mut self.recording, &event) {
+            if key.kind == KeyEventKind::Press {
+                steps.push(Step::Keys(vec![Event::Key(*key)]));
             }
         }
-        None
+        Ok(event)
+    }
+
+    /// Handles one input event the way the main loop does, opening dialogs on `terminal`.
+    fn handle_event<B: Backend>(
+        &mut self,
+        event: Event,
+        terminal: &mut Terminal<B>,
+    ) -> io::Result<()> {
+        match event {
+            Event::Key(key) => {
+                if key.kind != KeyEventKind::Press {
+                    return Ok(());
+                }
+                self.status_message = None;
+                let chord_len = self.pending_keys.len() + 1;
+                let key_match = self.get_action_for_key(&key);
+                if let KeyMatch::Action(action) = key_match {
+                    if action == "record_macro" || action.starts_with("macro:") {
+                        // A recording gets the macro's own steps, and never its stop key
+                        self.forget_recorded_keys(chord_len);
+                    }
+                    self.run_action(&action, terminal)?;
+                } else if matches!(key_match, KeyMatch::Unbound) && self.mode == InputMode::Insert {
+                    match key.code {
+                        KeyCode::Char(c) => {
+                            self.input.push(c);
+                            self.update_search();
+                            self.record_input();
+                        },
+                        KeyCode::Backspace => {
+                            self.input.pop();
+                            self.update_search();
+                            self.record_input();
+                        },
+                        _ => {},
+                    }
+                }
+            },
+            Event::Mouse(mouse_event) => match mouse_event.kind {
+                MouseEventKind::ScrollDown => self.next(),
+                MouseEventKind::ScrollUp => self.previous(),
+                MouseEventKind::Down(_button) => {
+                    self.handle_click(mouse_event.column, mouse_event.row, mouse_event.modifiers)
+                },
+                MouseEventKind::Drag(_button) => {
+                    self.drag_divider(mouse_event.column, mouse_event.row)
+                },
+                MouseEventKind::Up(_button) => self.release_divider(),
+                _ => {},
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    /// Runs a bound action, opening its dialog on `terminal` for the ones that have one.
+    fn run_action<B: Backend>(
+        &mut self,
+        action: &str,
+        terminal: &mut Terminal<B>,
+    ) -> io::Result<()> {
+        match action {
+            "show_help" => self.show_help(terminal),
+            "show_command_palette" => self.show_command_palette(terminal),
+            "show_statistics" => self.show_statistics(terminal),
+            "jump_to_skin" => self.show_jump_finder(terminal),
+            _ => {
+                match action.strip_prefix("macro:") {
+                    Some(name) => self.run_macro(name, terminal),
+                    None => self.handle_action(action),
+                }
+                Ok(())
+            },
+        }
     }
 
     fn handle_action(&mut self, action: &str) {
@@ -454,14 +1206,33 @@ impl AppState {
                 self.record_input();
             },
             "toggle_detail" => self.show_detail = !self.show_detail,
+            "toggle_explain" => self.explain = !self.explain,
+            "record_macro" => self.toggle_recording(),
+            "cycle_ranking_profile" => self.cycle_ranking_profile(),
             "undo_input" => self.undo(),
             "redo_input" => self.redo(),
             "toggle_favorite" => self.toggle_favorite(),
             "clear_favorites" => {
                 self.favorites.clear();
-                save_favorites(&self.favorites).expect("Failed to clear favorites");
+                self.store.save_favorites(&self.favorites).expect("Failed to clear favorites");
                 self.update_search();
             },
+            // The facet sidebar takes over navigation while it is open
+            "next_item" if self.show_facets => self.move_facet_cursor(1),
+            "previous_item" if self.show_facets => self.move_facet_cursor(-1),
+            "activate_chip" if self.show_facets && self.chip_focus.is_none() => {
+                let rows = self.facet_rows();
+                if let Some(Some(index)) = self.facet_state.selected().and_then(|r| rows.get(r)) {
+                    self.toggle_facet(*index);
+                }
+            },
+            "toggle_facets" => self.toggle_facets(),
+            "random_skin" => self.pick_random_skin(),
+            "copy_name" | "copy_rows" | "copy_query" | "copy_for_chat" => self.copy_action(action),
+            "grow_table" => self.resize_table(5),
+            "shrink_table" => self.resize_table(-5),
+            "toggle_suggestions" => self.toggle_suggestions(),
+            "show_daily_skin" => self.show_daily_skin(),
             "next_item" => self.next(),
             "previous_item" => self.previous(),
             "first_page" => self.first_page(),
@@ -482,6 +1253,12 @@ impl AppState {
             "cycle_suggestion_next" => self.cycle_suggestion(1),
             "cycle_suggestion_prev" => self.cycle_suggestion(-1),
             "accept_suggestion" => self.accept_suggestion(),
+            "focus_next_chip" => self.focus_next_chip(),
+            "activate_chip" => self.activate_chip(false),
+            "exclude_chip" => self.activate_chip(true),
+            "toggle_vim_mode" => self.toggle_vim_mode(),
+            "focus_search" => self.mode = InputMode::Insert,
+            "enter_normal_mode" => self.mode = InputMode::Normal,
             "exit" => self.should_exit = true,
             _ => {},
         }
@@ -497,14 +1274,16 @@ impl AppState {
                     .constraints([Constraint::Percentage(100)].as_ref())
                     .split(size)[0];
 
-                let title = if self.editing_keybinds {
+                let title = if let Some(message) = &self.help_message {
+                    Span::styled(message.clone(), Style::default().fg(D_RED))
+                } else if self.editing_keybinds {
                     if self.awaiting_key.is_some() {
-                        "Help - Editing... (Press new key)"
+                        Span::raw("Help - Editing... (Press new key)")
                     } else {
-                        "Help - Edit Mode (Enter to rebind, E to exit edit)"
+                        Span::raw("Help - Edit Mode (Enter to rebind, R to reset, E to exit edit)")
                     }
                 } else {
-                    "Help (E to edit keybinds, ▲/▼ or scroll to select)"
+                    Span::raw("Help (E to edit keybinds, ▲/▼ or scroll to select)")
                 };
 
                 let block = Block::default()
@@ -514,13 +1293,14 @@ impl AppState {
                     .border_style(Style::default().fg(D_CYAN));
 
                 let actions = self.get_help_actions();
+                let conflicts = find_key_conflicts(&self.key_bindings);
                 let items: Vec<ListItem> = actions
                     .iter()
                     .map(|(action, description)| {
                         let binding = self
                             .key_bindings
                             .get(*action)
-                            .map(|&b| get_key_binding_str(&b))
+                            .map(|chord| get_key_chord_str(chord))
                             .unwrap_or_else(|| "Not bound".to_string());
                         let is_awaiting = self.awaiting_key.as_deref() == Some(*action);
                         let content = if is_awaiting {
@@ -530,14 +1310,26 @@ impl AppState {
                                 Span::styled(*description, Style::default().fg(D_FOREGROUND)),
                             ])
                         } else {
-                            Line::from(vec![
+                            let mut spans = vec![
                                 Span::styled(
                                     format!("[{}] ", binding),
                                     Style::default().fg(D_CYAN),
                                 ),
                                 Span::styled(": ", Style::default().fg(D_FOREGROUND)),
                                 Span::styled(*description, Style::default().fg(D_FOREGROUND)),
-                            ])
+                            ];
+                            for (a, b) in &conflicts {
+                                let other = match *action {
+                                    _ if a == action => b,
+                                    _ if b == action => a,
+                                    _ => continue,
+                                };
+                                spans.push(Span::styled(
+                                    format!(" ⚠ conflicts with {}", other),
+                                    Style::default().fg(D_RED),
+                                ));
+                            }
+                            Line::from(spans)
                         };
                         ListItem::new(content)
                     })
@@ -547,21 +1339,55 @@ impl AppState {
                     Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND).add_modifier(Modifier::BOLD),
                 );
 
-                f.render_stateful_widget(list, modal_area, &mut self.help_state);
+                if self.key_warnings.is_empty() {
+                    f.render_stateful_widget(list, modal_area, &mut self.help_state);
+                } else {
+                    let chunks = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([
+                            Constraint::Min(3),
+                            Constraint::Length(self.key_warnings.len() as u16 + 2),
+                        ])
+                        .split(modal_area);
+                    f.render_stateful_widget(list, chunks[0], &mut self.help_state);
+                    let warnings: Vec<Line> = self
+                        .key_warnings
+                        .iter()
+                        .map(|w| Line::from(Span::styled(w.clone(), Style::default().fg(D_RED))))
+                        .collect();
+                    let warnings = Paragraph::new(warnings).block(
+                        Block::default()
+                            .title("key_bindings.json")
+                            .borders(Borders::ALL)
+                            .border_type(BorderType::Rounded)
+                            .border_style(Style::default().fg(D_RED)),
+                    );
+                    f.render_widget(warnings, chunks[1]);
+                }
             })?;
 
-            if event::poll(Duration::from_millis(50))? {
-                match event::read()? {
+            if let Some(event) = self.poll_event()? {
+                match event {
                     Event::Key(key) => {
                         if key.kind != KeyEventKind::Press {
                             continue;
                         }
-                        if let Some(awaiting_action) = self.awaiting_key.clone() {
-                            // Capture the new keybinding
-                            self.key_bindings
-                                .insert(awaiting_action.clone(), (key.code, key.modifiers));
-                            self.awaiting_key = None;
-                            save_default_key_bindings(&self.key_bindings)?;
+                        self.help_message = None;
+                        if let Some(awaiting_action) = self.awaiting_key.take() {
+                            // Capture the new keybinding, unless another action already has it
+                            let chord = vec![(key.code, key.modifiers)];
+                            if let Some(other) =
+                                binding_conflict(&self.key_bindings, &awaiting_action, &chord)
+                            {
+                                self.help_message = Some(format!(
+                                    "{} is already bound to {}",
+                                    get_key_chord_str(&chord),
+                                    other
+                                ));
+                            } else {
+                                self.key_bindings.insert(awaiting_action, chord);
+                                save_default_key_bindings(&self.key_bindings)?;
+                            }
                         } else if key.code == KeyCode::Esc {
                             break;
                         } else if key.code == KeyCode::Char('e') || key.code == KeyCode::Char('E') {
@@ -585,6 +1411,14 @@ impl AppState {
                                         self.awaiting_key = Some(action);
                                     }
                                 },
+                                KeyCode::Char('r') | KeyCode::Char('R')
+                                    if self.editing_keybinds =>
+                                {
+                                    if let Some(i) = self.help_state.selected() {
+                                        let action = self.get_help_actions()[i].0;
+                                        self.reset_key_binding(action)?;
+                                    }
+                                },
                                 _ => {},
                             }
                         }
@@ -629,11 +1463,426 @@ impl AppState {
         Ok(())
     }
 
+    /// Palette entries matching the command word typed so far, as `(command, binding,
+    /// description)`. Argument commands have no binding and show their usage instead.
+    fn palette_entries(&self) -> Vec<(String, String, &'static str)> {
+        let mut entries: Vec<(String, String, &'static str)> = self
+            .get_help_actions()
+            .into_iter()
+            .map(|(action, description)| {
+                let binding = self
+                    .key_bindings
+                    .get(action)
+                    .map(|chord| get_key_chord_str(chord))
+                    .unwrap_or_else(|| "Not bound".to_string());
+                (action.to_string(), binding, description)
+            })
+            .collect();
+        entries.extend(
+            PALETTE_COMMANDS
+                .iter()
+                .map(|&(usage, description)| (usage.to_string(), String::new(), description)),
+        );
+
+        let command = self.palette_input.split_whitespace().next().unwrap_or("").to_lowercase();
+        if command.is_empty() {
+            return entries;
+        }
+        let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+        let mut scored: Vec<(i64, (String, String, &'static str))> = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let name = entry.0.split_whitespace().next().unwrap_or("");
+                let name_score = matcher.fuzzy_match(name, &command).map(|s| s * 2);
+                let description_score = matcher.fuzzy_match(entry.2, &command);
+                name_score.max(description_score).map(|score| (score, entry))
+            })
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Runs a palette command with arguments, returning a message for the status bar.
+    fn run_command(&mut self, input: &str) -> Result<String, String> {
+        let parts: Vec<&str> = input.split_whitespace().collect();
+        match parts.first().map(|p| p.to_lowercase()).as_deref() {
+            Some("sort") => {
+                let usage = "Usage: sort <name|rarity|event|year> [asc|desc] ...";
+                let mut keys: Vec<SortKey> = Vec::new();
+                for part in &parts[1..] {
+                    match (part.to_lowercase().as_str(), keys.last_mut()) {
+                        ("asc", Some(key)) => key.descending = false,
+                        ("desc", Some(key)) => key.descending = true,
+                        (field, _) => {
+                            let field = SortField::parse(field).ok_or(usage.to_string())?;
+                            keys.push(SortKey { field, descending: false });
+                        },
+                    }
+                }
+                if keys.is_empty() {
+                    return Err(usage.to_string());
+                }
+                let description: Vec<String> = keys
+                    .iter()
+                    .map(|key| {
+                        let direction = if key.descending { "descending" } else { "ascending" };
+                        format!("{} {}", key.field.label(), direction)
+                    })
+                    .collect();
+                self.sort_keys = keys;
+                self.sort_results();
+                self.first_page();
+                Ok(format!("Sorted by {}", description.join(", then ")))
+            },
+            Some("export") => {
+                let usage = "Usage: export <csv|tsv|json|txt> <path>";
+                let format =
+                    parts.get(1).and_then(|f| ExportFormat::parse(f)).ok_or(usage.to_string())?;
+                if parts.len() < 3 {
+                    return Err(usage.to_string());
+                }
+                let path = export_skins(&self.results, format, &parts[2..].join(" "))
+                    .map_err(|e| format!("Export failed: {}", e))?;
+                Ok(format!("Exported {} skins to {}", self.results.len(), path.display()))
+            },
+            Some("chat") => {
+                let usage = "Usage: chat <discord|markdown> [all]";
+                let format =
+                    parts.get(1).and_then(|f| ChatFormat::parse(f)).ok_or(usage.to_string())?;
+                match parts.get(2).map(|p| p.to_lowercase()).as_deref() {
+                    Some("all") => self.copy_for_chat(&self.results.clone(), format),
+                    None => {
+                        let skin = self.selected_skin().cloned().ok_or("No skin selected")?;
+                        self.copy_for_chat(&[skin], format)
+                    },
+                    Some(_) => Err(usage.to_string()),
+                }
+            },
+            _ => Err(format!("Unknown command: {}", input)),
+        }
+    }
+
+    fn show_command_palette<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        self.palette_input.clear();
+        self.palette_error = None;
+        self.palette_state.select(Some(0));
+
+        loop {
+            let entries = self.palette_entries();
+            terminal.draw(|f| {
+                ui(f, self);
+
+                let area = f.size();
+                let width = area.width.saturating_sub(4).min(70);
+                let height = area.height.saturating_sub(4).min(20);
+                let palette_area =
+                    Rect::new(area.x + (area.width - width) / 2, area.y + 2, width, height);
+                f.render_widget(Clear, palette_area);
+
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(3), Constraint::Min(1)])
+                    .split(palette_area);
+
+                let title = match &self.palette_error {
+                    Some(error) => Span::styled(error.clone(), Style::default().fg(D_RED)),
+                    None => Span::raw("Command Palette (Enter to run, Esc to close)"),
+                };
+                let input = Paragraph::new(self.palette_input.as_str()).block(
+                    Block::default()
+                        .title(title)
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(D_CYAN)),
+                );
+                f.render_widget(input, chunks[0]);
+                f.set_cursor(chunks[0].x + 1 + self.palette_input.len() as u16, chunks[0].y + 1);
+
+                let items: Vec<ListItem> = entries
+                    .iter()
+                    .map(|(command, binding, description)| {
+                        let mut spans =
+                            vec![Span::styled(command.clone(), Style::default().fg(D_PINK))];
+                        if !binding.is_empty() {
+                            spans.push(Span::styled(
+                                format!(" [{}]", binding),
+                                Style::default().fg(D_CYAN),
+                            ));
+                        }
+                        spans.push(Span::styled(
+                            format!(" : {}", description),
+                            Style::default().fg(D_FOREGROUND),
+                        ));
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+                let list = List::new(items)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_type(BorderType::Rounded)
+                            .border_style(Style::default().fg(D_CYAN)),
+                    )
+                    .highlight_style(
+                        Style::default()
+                            .bg(D_BACKGROUND)
+                            .fg(D_FOREGROUND)
+                            .add_modifier(Modifier::BOLD),
+                    );
+                f.render_stateful_widget(list, chunks[1], &mut self.palette_state);
+            })?;
+
+            let Some(Event::Key(key)) = self.poll_event()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Esc => break,
+                KeyCode::Up => {
+                    let i = self.palette_state.selected().unwrap_or(0).saturating_sub(1);
+                    self.palette_state.select(Some(i));
+                },
+                KeyCode::Down => {
+                    let i = self.palette_state.selected().unwrap_or(0) + 1;
+                    self.palette_state.select(Some(i.min(entries.len().saturating_sub(1))));
+                },
+                KeyCode::Tab => {
+                    if let Some((command, _, _)) =
+                        entries.get(self.palette_state.selected().unwrap_or(0))
+                    {
+                        let name = command.split_whitespace().next().unwrap_or("");
+                        self.palette_input = format!("{} ", name);
+                        self.palette_state.select(Some(0));
+                    }
+                },
+                KeyCode::Backspace => {
+                    self.palette_input.pop();
+                    self.palette_error = None;
+                    self.palette_state.select(Some(0));
+                },
+                KeyCode::Char(c) => {
+                    self.palette_input.push(c);
+                    self.palette_error = None;
+                    self.palette_state.select(Some(0));
+                },
+                KeyCode::Enter => {
+                    let input = self.palette_input.trim().to_string();
+                    let command = input.split_whitespace().next().unwrap_or("").to_lowercase();
+                    if PALETTE_COMMANDS.iter().any(|(usage, _)| usage.starts_with(&command))
+                        && input.contains(' ')
+                    {
+                        match self.run_command(&input) {
+                            Ok(message) => {
+                                self.status_message = Some(message);
+                                break;
+                            },
+                            Err(error) => self.palette_error = Some(error),
+                        }
+                        continue;
+                    }
+
+                    let Some((selected, _, _)) =
+                        entries.get(self.palette_state.selected().unwrap_or(0)).cloned()
+                    else {
+                        continue;
+                    };
+                    if selected.contains(' ') {
+                        // Argument commands are completed rather than run
+                        let name = selected.split_whitespace().next().unwrap_or("");
+                        self.palette_input = format!("{} ", name);
+                        self.palette_state.select(Some(0));
+                        continue;
+                    }
+                    match selected.as_str() {
+                        "show_help" => self.show_help(terminal)?,
+                        "show_statistics" => self.show_statistics(terminal)?,
+                        "jump_to_skin" => self.show_jump_finder(terminal)?,
+                        "show_command_palette" => {},
+                        action => self.handle_action(action),
+                    }
+                    break;
+                },
+                _ => {},
+            }
+        }
+        self.palette_input.clear();
+        self.palette_error = None;
+        Ok(())
+    }
+
+    /// Skin names matching the jump finder's input, best first, and whether each one is in the
+    /// current results.
+    fn jump_entries(&self) -> Vec<(String, bool)> {
+        let in_results: HashSet<&str> = self.results.iter().map(|s| s.name.as_str()).collect();
+        let query = self.jump_input.trim().to_lowercase();
+        if query.is_empty() {
+            return self.results.iter().map(|s| (s.name.clone(), true)).collect();
+        }
+        let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+        let mut scored: Vec<(i64, &str)> = self
+            .name_map
+            .iter()
+            .filter_map(|(name_lower, &i)| {
+                matcher
+                    .fuzzy_match(name_lower, &query)
+                    .map(|score| (score, self.skins[i].name.as_str()))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(b.1)));
+        scored.into_iter().map(|(_, name)| (name.to_string(), in_results.contains(name))).collect()
+    }
+
+    /// Fuzzy finder over skin names that moves the table to the pick, leaving the query and
+    /// results alone. The table follows the highlighted name while typing.
+    fn show_jump_finder<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        let origin = (self.current_page, self.table_state.selected());
+        self.jump_input.clear();
+        self.jump_state.select(Some(0));
+
+        loop {
+            let entries = self.jump_entries();
+            if let Some((name, true)) = entries.get(self.jump_state.selected().unwrap_or(0)) {
+                self.select_skin(name);
+            }
+            terminal.draw(|f| {
+                ui(f, self);
+
+                let area = f.size();
+                let width = area.width.saturating_sub(4).min(50);
+                let height = area.height.saturating_sub(4).min(16);
+                let finder_area =
+                    Rect::new(area.x + (area.width - width) / 2, area.y + 2, width, height);
+                f.render_widget(Clear, finder_area);
+
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(3), Constraint::Min(1)])
+                    .split(finder_area);
+
+                let input = Paragraph::new(self.jump_input.as_str()).block(
+                    Block::default()
+                        .title("Jump to Skin (Enter to jump, Esc to go back)")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(D_CYAN)),
+                );
+                f.render_widget(input, chunks[0]);
+                f.set_cursor(chunks[0].x + 1 + self.jump_input.len() as u16, chunks[0].y + 1);
+
+                let items: Vec<ListItem> = entries
+                    .iter()
+                    .map(|(name, in_results)| {
+                        if *in_results {
+                            ListItem::new(Span::styled(name.clone(), Style::default().fg(D_PINK)))
+                        } else {
+                            ListItem::new(Line::from(vec![
+                                Span::styled(name.clone(), Style::default().fg(Color::DarkGray)),
+                                Span::styled(
+                                    " (not in results)",
+                                    Style::default().fg(Color::DarkGray),
+                                ),
+                            ]))
+                        }
+                    })
+                    .collect();
+                let list = List::new(items)
+                    .block(
+                        Block::default()
+                            .title(format!("{} skins", entries.len()))
+                            .borders(Borders::ALL)
+                            .border_type(BorderType::Rounded)
+                            .border_style(Style::default().fg(D_CYAN)),
+                    )
+                    .highlight_style(
+                        Style::default()
+                            .bg(D_BACKGROUND)
+                            .fg(D_FOREGROUND)
+                            .add_modifier(Modifier::BOLD),
+                    );
+                f.render_stateful_widget(list, chunks[1], &mut self.jump_state);
+            })?;
+
+            let Some(Event::Key(key)) = self.poll_event()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Esc => {
+                    self.current_page = origin.0;
+                    self.table_state.select(origin.1);
+                    break;
+                },
+                KeyCode::Up => {
+                    let i = self.jump_state.selected().unwrap_or(0).saturating_sub(1);
+                    self.jump_state.select(Some(i));
+                },
+                KeyCode::Down => {
+                    let i = self.jump_state.selected().unwrap_or(0) + 1;
+                    self.jump_state.select(Some(i.min(entries.len().saturating_sub(1))));
+                },
+                KeyCode::Backspace => {
+                    self.jump_input.pop();
+                    self.jump_state.select(Some(0));
+                },
+                KeyCode::Char(c) => {
+                    self.jump_input.push(c);
+                    self.jump_state.select(Some(0));
+                },
+                KeyCode::Enter => match entries.get(self.jump_state.selected().unwrap_or(0)) {
+                    Some((_, true)) => break,
+                    Some((name, false)) => {
+                        self.status_message =
+                            Some(format!("{} isn't in the current results", name));
+                    },
+                    None => {},
+                },
+                _ => {},
+            }
+        }
+        self.jump_input.clear();
+        Ok(())
+    }
+
+    fn show_statistics<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        // Start on the current results when a query narrows them, otherwise on the catalog
+        let mut whole_catalog = self.input.trim().is_empty();
+        loop {
+            let (skins, scope) = if whole_catalog {
+                (&self.skins, "Whole catalog")
+            } else {
+                (&self.results, "Current results")
+            };
+            let stats = SkinStats::compute(skins, &self.favorites);
+            terminal.draw(|f| render_statistics(f, &stats, scope))?;
+
+            if let Some(Event::Key(key)) = self.poll_event()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Esc => break,
+                    KeyCode::Tab | KeyCode::BackTab => whole_catalog = !whole_catalog,
+                    _ => {},
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn get_help_actions(&self) -> Vec<(&'static str, &'static str)> {
         vec![
             ("clear_search", "Clear search bar"),
             ("show_help", "Show this help page"),
+            ("show_command_palette", "Open the command palette"),
+            ("show_statistics", "Show catalog and result statistics"),
+            ("jump_to_skin", "Jump to a skin by name without changing the search"),
             ("toggle_detail", "Toggle detailed view"),
+            ("toggle_explain", "Explain why each result matched"),
+            ("cycle_ranking_profile", "Switch to the next ranking profile"),
             ("undo_input", "Undo input in search bar"),
             ("redo_input", "Redo input in search bar"),
             ("toggle_favorite", "Add favorite tag to current selected skin"),
@@ -647,12 +1896,53 @@ impl AppState {
             ("cycle_suggestion_next", "Cycle suggestions forward"),
             ("cycle_suggestion_prev", "Cycle suggestions backward"),
             ("accept_suggestion", "Accept suggestion and auto-fill (►)"),
+            ("focus_next_chip", "Focus the next rarity/tag chip in the detail panel"),
+            ("activate_chip", "Filter by the focused chip"),
+            ("exclude_chip", "Exclude the focused chip from results"),
+            ("toggle_facets", "Toggle the filter sidebar (▲/▼ and Enter pick filters)"),
+            ("random_skin", "Jump to a random skin from the results"),
+            ("copy_name", "Copy the selected skin's name"),
+            ("copy_rows", "Copy the rows on this page as TSV"),
+            ("copy_query", "Copy the search query"),
+            ("copy_for_chat", "Copy the selected skin for pasting into Discord"),
+            ("record_macro", "Start or stop recording a macro"),
+            ("grow_table", "Widen the results table (drag the detail panel edge too)"),
+            ("shrink_table", "Narrow the results table"),
+            ("toggle_suggestions", "Show or hide the suggestion box"),
+            ("show_daily_skin", "Jump to the skin of the day"),
+            ("toggle_vim_mode", "Toggle vim-style normal/insert modes"),
+            ("normal:next_item", "Normal mode: next item"),
+            ("normal:previous_item", "Normal mode: previous item"),
+            ("normal:first_page", "Normal mode: jump to first result"),
+            ("normal:last_page", "Normal mode: jump to last result"),
+            ("normal:focus_search", "Normal mode: focus the search bar (insert mode)"),
+            ("normal:toggle_favorite", "Normal mode: favorite current selected skin"),
+            ("normal:show_command_palette", "Normal mode: open the command palette"),
+            ("insert:enter_normal_mode", "Insert mode: return to normal mode"),
             ("exit", "Exit application"),
         ]
     }
 }
 
 fn main() -> io::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("generate") => {
+            let up_to_date = generate::run(&args[1..])?;
+            std::process::exit(if up_to_date { 0 } else { 1 });
+        },
+        Some("serve") => return serve::run(&args[1..]),
+        Some("lint-catalog") => {
+            let clean = lint::run(&args[1..])?;
+            std::process::exit(if clean { 0 } else { 1 });
+        },
+        Some("script") => {
+            let succeeded = script::run(&args[1..])?;
+            std::process::exit(if succeeded { 0 } else { 1 });
+        },
+        _ => {},
+    }
+
     enable_raw_mode()?;
     execute!(
         io::stdout(),
@@ -670,90 +1960,8 @@ fn main() -> io::Result<()> {
     while !app.should_exit {
         terminal.draw(|f| ui(f, &mut app))?;
 
-        if event::poll(Duration::from_millis(50))? {
-            match event::read()? {
-                Event::Key(key) => {
-                    if key.kind != KeyEventKind::Press {
-                        continue;
-                    }
-                    if let Some(action) = app.get_action_for_key(&key) {
-                        if action == "show_help" {
-                            app.show_help(&mut terminal)?;
-                        } else {
-                            app.handle_action(&action);
-                        }
-                    } else {
-                        match key.code {
-                            KeyCode::Char(c) => {
-                                app.input.push(c);
-                                app.update_search();
-                                app.record_input();
-                            },
-                            KeyCode::Backspace => {
-                                app.input.pop();
-                                app.update_search();
-                                app.record_input();
-                            },
-                            _ => {},
-                        }
-                    }
-                },
-                Event::Mouse(mouse_event) => match mouse_event.kind {
-                    MouseEventKind::ScrollDown => {
-                        app.scroll_offset = app.scroll_offset.saturating_add(1);
-                        app.next();
-                    },
-                    MouseEventKind::ScrollUp => {
-                        app.scroll_offset = app.scroll_offset.saturating_sub(1);
-                        app.previous();
-                    },
-                    MouseEventKind::Down(_button) => {
-                        let term_area = terminal.size()?;
-                        let outer_chunks = Layout::default()
-                            .direction(Direction::Vertical)
-                            .margin(1)
-                            .constraints([
-                                Constraint::Length(3),
-                                Constraint::Length(5),
-                                Constraint::Min(3),
-                                Constraint::Length(1),
-                            ])
-                            .split(term_area);
-                        let main_chunks = Layout::default()
-                            .direction(Direction::Horizontal)
-                            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-                            .split(outer_chunks[2]);
-                        let table_area = main_chunks[0];
-
-                        let inner_y = table_area.y + 1;
-                        let header_height = 1;
-
-                        if mouse_event.row == inner_y {
-                            let relative_x = mouse_event.column.saturating_sub(table_area.x);
-                            let table_width = table_area.width;
-                            let name_width = (table_width as f32 * 0.30).round() as u16;
-                            let rarity_width = (table_width as f32 * 0.10).round() as u16;
-                            let event_width = (table_width as f32 * 0.25).round() as u16;
-                            if relative_x < name_width {
-                                app.toggle_sort(SortField::Name);
-                            } else if relative_x < name_width + rarity_width {
-                                app.toggle_sort(SortField::Rarity);
-                            } else if relative_x < name_width + rarity_width + event_width {
-                                app.toggle_sort(SortField::Event);
-                            }
-                        } else {
-                            let results_start_y = inner_y + header_height;
-                            let visible_index = (mouse_event.row - results_start_y) as usize;
-                            let absolute_index = app.scroll_offset + visible_index;
-                            if absolute_index < app.results.len() {
-                                app.table_state.select(Some(absolute_index));
-                            }
-                        }
-                    },
-                    _ => {},
-                },
-                _ => {},
-            }
+        if let Some(event) = app.poll_event()? {
+            app.handle_event(event, &mut terminal)?;
         }
     }
 
@@ -768,83 +1976,189 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-fn load_favorites() -> io::Result<HashSet<String>> {
-    let path = "favorites.txt";
-    if let Ok(content) = fs::read_to_string(path) {
-        Ok(content.lines().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
-    } else {
-        Ok(HashSet::new())
-    }
-}
-
-fn save_favorites(favorites: &HashSet<String>) -> io::Result<()> {
-    let path = "favorites.txt";
-    let content = favorites.iter().map(|s| s.as_str()).collect::<Vec<_>>().join("\n");
-    fs::write(path, content)
-}
-
 fn get_key_config_path() -> PathBuf {
-    let mut path = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
-    path.push(".skin_tui");
-    path.push("key_bindings.json");
-    path
+    config_file("key_bindings.json")
 }
 
-fn default_key_bindings() -> HashMap<String, (KeyCode, KeyModifiers)> {
+fn default_key_bindings() -> HashMap<String, KeyChord> {
     let mut bindings = HashMap::new();
-    bindings.insert("clear_search".to_string(), (KeyCode::Char('l'), KeyModifiers::CONTROL));
-    bindings.insert("show_help".to_string(), (KeyCode::Char('h'), KeyModifiers::CONTROL));
-    bindings.insert("toggle_detail".to_string(), (KeyCode::Char('d'), KeyModifiers::CONTROL));
-    bindings.insert("undo_input".to_string(), (KeyCode::Char('z'), KeyModifiers::CONTROL));
-    bindings.insert("redo_input".to_string(), (KeyCode::Char('y'), KeyModifiers::CONTROL));
-    bindings.insert("toggle_favorite".to_string(), (KeyCode::Char('f'), KeyModifiers::CONTROL));
-    bindings.insert("clear_favorites".to_string(), (KeyCode::Char('F'), KeyModifiers::SHIFT));
-    bindings.insert("next_item".to_string(), (KeyCode::Down, KeyModifiers::NONE));
-    bindings.insert("previous_item".to_string(), (KeyCode::Up, KeyModifiers::NONE));
-    bindings.insert("first_page".to_string(), (KeyCode::Home, KeyModifiers::NONE));
-    bindings.insert("last_page".to_string(), (KeyCode::End, KeyModifiers::NONE));
-    bindings.insert("page_up".to_string(), (KeyCode::PageUp, KeyModifiers::NONE));
-    bindings.insert("page_down".to_string(), (KeyCode::PageDown, KeyModifiers::NONE));
-    bindings.insert("cycle_suggestion_next".to_string(), (KeyCode::Tab, KeyModifiers::NONE));
-    bindings.insert("cycle_suggestion_prev".to_string(), (KeyCode::Tab, KeyModifiers::SHIFT));
-    bindings.insert("accept_suggestion".to_string(), (KeyCode::Right, KeyModifiers::NONE));
-    bindings.insert("exit".to_string(), (KeyCode::Esc, KeyModifiers::NONE));
+    bindings.insert("clear_search".to_string(), vec![(KeyCode::Char('l'), KeyModifiers::CONTROL)]);
+    bindings.insert("show_help".to_string(), vec![(KeyCode::Char('h'), KeyModifiers::CONTROL)]);
+    bindings.insert(
+        "show_command_palette".to_string(),
+        vec![(KeyCode::Char('p'), KeyModifiers::CONTROL)],
+    );
+    bindings
+        .insert("show_statistics".to_string(), vec![(KeyCode::Char('s'), KeyModifiers::CONTROL)]);
+    bindings.insert("toggle_detail".to_string(), vec![(KeyCode::Char('d'), KeyModifiers::CONTROL)]);
+    bindings
+        .insert("toggle_explain".to_string(), vec![(KeyCode::Char('e'), KeyModifiers::CONTROL)]);
+    bindings.insert(
+        "cycle_ranking_profile".to_string(),
+        vec![(KeyCode::Char('k'), KeyModifiers::CONTROL)],
+    );
+    bindings.insert("undo_input".to_string(), vec![(KeyCode::Char('z'), KeyModifiers::CONTROL)]);
+    bindings.insert("redo_input".to_string(), vec![(KeyCode::Char('y'), KeyModifiers::CONTROL)]);
+    bindings
+        .insert("toggle_favorite".to_string(), vec![(KeyCode::Char('f'), KeyModifiers::CONTROL)]);
+    bindings.insert("clear_favorites".to_string(), vec![(KeyCode::Char('F'), KeyModifiers::SHIFT)]);
+    bindings.insert("next_item".to_string(), vec![(KeyCode::Down, KeyModifiers::NONE)]);
+    bindings.insert("previous_item".to_string(), vec![(KeyCode::Up, KeyModifiers::NONE)]);
+    bindings.insert("first_page".to_string(), vec![(KeyCode::Home, KeyModifiers::NONE)]);
+    bindings.insert("last_page".to_string(), vec![(KeyCode::End, KeyModifiers::NONE)]);
+    bindings.insert("page_up".to_string(), vec![(KeyCode::PageUp, KeyModifiers::NONE)]);
+    bindings.insert("page_down".to_string(), vec![(KeyCode::PageDown, KeyModifiers::NONE)]);
+    bindings.insert("cycle_suggestion_next".to_string(), vec![(KeyCode::Tab, KeyModifiers::NONE)]);
+    // Terminals report Shift+Tab as BackTab
+    bindings
+        .insert("cycle_suggestion_prev".to_string(), vec![(KeyCode::BackTab, KeyModifiers::NONE)]);
+    bindings.insert("accept_suggestion".to_string(), vec![(KeyCode::Right, KeyModifiers::NONE)]);
+    bindings
+        .insert("focus_next_chip".to_string(), vec![(KeyCode::Char('t'), KeyModifiers::CONTROL)]);
+    bindings.insert("toggle_facets".to_string(), vec![(KeyCode::Char('b'), KeyModifiers::CONTROL)]);
+    bindings.insert("jump_to_skin".to_string(), vec![(KeyCode::Char('j'), KeyModifiers::CONTROL)]);
+    bindings.insert("grow_table".to_string(), vec![(KeyCode::Right, KeyModifiers::CONTROL)]);
+    bindings.insert("shrink_table".to_string(), vec![(KeyCode::Left, KeyModifiers::CONTROL)]);
+    bindings.insert(
+        "toggle_suggestions".to_string(),
+        vec![(KeyCode::Char('w'), KeyModifiers::CONTROL)],
+    );
+    bindings.insert("random_skin".to_string(), vec![(KeyCode::Char('r'), KeyModifiers::CONTROL)]);
+    bindings.insert("copy_name".to_string(), vec![(KeyCode::Char('c'), KeyModifiers::ALT)]);
+    bindings.insert("copy_rows".to_string(), vec![(KeyCode::Char('r'), KeyModifiers::ALT)]);
+    bindings.insert("copy_query".to_string(), vec![(KeyCode::Char('q'), KeyModifiers::ALT)]);
+    bindings.insert("copy_for_chat".to_string(), vec![(KeyCode::Char('d'), KeyModifiers::ALT)]);
+    bindings.insert("record_macro".to_string(), vec![(KeyCode::Char('m'), KeyModifiers::ALT)]);
+    bindings
+        .insert("show_daily_skin".to_string(), vec![(KeyCode::Char('o'), KeyModifiers::CONTROL)]);
+    bindings.insert("activate_chip".to_string(), vec![(KeyCode::Enter, KeyModifiers::NONE)]);
+    bindings.insert("exclude_chip".to_string(), vec![(KeyCode::Char('n'), KeyModifiers::CONTROL)]);
+    bindings
+        .insert("toggle_vim_mode".to_string(), vec![(KeyCode::Char('g'), KeyModifiers::CONTROL)]);
+    bindings.insert("exit".to_string(), vec![(KeyCode::Esc, KeyModifiers::NONE)]);
+
+    // Only active with vim mode on
+    let normal = [
+        ("next_item", KeyCode::Char('j')),
+        ("previous_item", KeyCode::Char('k')),
+        ("last_page", KeyCode::Char('G')),
+        ("focus_search", KeyCode::Char('/')),
+        ("toggle_favorite", KeyCode::Char('f')),
+        ("show_command_palette", KeyCode::Char(':')),
+    ];
+    for (action, key) in normal {
+        bindings.insert(format!("normal:{}", action), vec![(key, KeyModifiers::NONE)]);
+    }
+    bindings.insert(
+        "normal:first_page".to_string(),
+        vec![(KeyCode::Char('g'), KeyModifiers::NONE), (KeyCode::Char('g'), KeyModifiers::NONE)],
+    );
+    bindings
+        .insert("insert:enter_normal_mode".to_string(), vec![(KeyCode::Esc, KeyModifiers::NONE)]);
     bindings
 }
 
-fn save_default_key_bindings(
-    bindings: &HashMap<String, (KeyCode, KeyModifiers)>,
-) -> io::Result<()> {
+fn save_default_key_bindings(bindings: &HashMap<String, KeyChord>) -> io::Result<()> {
     let path = get_key_config_path();
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
     let mut raw_bindings = HashMap::new();
     for (action, binding) in bindings {
-        raw_bindings.insert(action.clone(), get_key_binding_str(binding));
+        raw_bindings.insert(action.clone(), get_key_chord_str(binding));
     }
     let json = serde_json::to_string_pretty(&raw_bindings)?;
     fs::write(path, json)?;
     Ok(())
 }
 
-fn load_key_bindings() -> io::Result<HashMap<String, (KeyCode, KeyModifiers)>> {
+/// Loads the key bindings along with warnings for unknown actions and unparseable keys.
+fn load_key_bindings() -> io::Result<(HashMap<String, KeyChord>, Vec<String>)> {
     let path = get_key_config_path();
     if path.exists() {
         let content = fs::read_to_string(&path)?;
         let raw_bindings: HashMap<String, String> = serde_json::from_str(&content)?;
+        let defaults = default_key_bindings();
         let mut bindings = HashMap::new();
+        let mut warnings = Vec::new();
         for (action, key_str) in raw_bindings {
-            if let Some(binding) = parse_key_binding(&key_str) {
-                bindings.insert(action, binding);
+            if !defaults.contains_key(&action) && !action.starts_with("macro:") {
+                warnings.push(format!("Unknown action \"{}\"", action));
+            }
+            match parse_key_chord(&key_str) {
+                Some(binding) => {
+                    bindings.insert(action, binding);
+                },
+                None => warnings.push(format!(
+                    "Can't parse key \"{}\" for {}, using the default",
+                    key_str, action
+                )),
             }
         }
-        Ok(bindings)
+        // Actions added after the file was written fall back to their defaults
+        for (action, binding) in defaults {
+            bindings.entry(action).or_insert(binding);
+        }
+        warnings.sort();
+        Ok((bindings, warnings))
     } else {
-        Ok(default_key_bindings())
+        Ok((default_key_bindings(), Vec::new()))
+    }
+}
+
+/// Pairs of known actions whose bindings collide in the same mode: the same keys, or one
+/// chord starting with the other so the longer one can never fire.
+fn find_key_conflicts(bindings: &HashMap<String, KeyChord>) -> Vec<(String, String)> {
+    let defaults = default_key_bindings();
+    let mut actions: Vec<&String> =
+        bindings.keys().filter(|a| defaults.contains_key(*a) || a.starts_with("macro:")).collect();
+    actions.sort();
+    let mut conflicts = Vec::new();
+    for (i, a) in actions.iter().enumerate() {
+        for b in &actions[i + 1..] {
+            if key_scope(a) == key_scope(b) && chords_overlap(&bindings[*a], &bindings[*b]) {
+                conflicts.push((a.to_string(), b.to_string()));
+            }
+        }
+    }
+    conflicts
+}
+
+/// The action that `chord` would collide with if it were bound to `action`.
+fn binding_conflict(
+    bindings: &HashMap<String, KeyChord>,
+    action: &str,
+    chord: &[(KeyCode, KeyModifiers)],
+) -> Option<String> {
+    let defaults = default_key_bindings();
+    let mut others: Vec<&String> = bindings
+        .iter()
+        .filter(|(other, bound)| {
+            other.as_str() != action
+                && (defaults.contains_key(*other) || other.starts_with("macro:"))
+                && key_scope(other) == key_scope(action)
+                && chords_overlap(bound, chord)
+        })
+        .map(|(other, _)| other)
+        .collect();
+    others.sort();
+    others.first().map(|other| other.to_string())
+}
+
+/// The mode an action's binding applies in: `normal`, `insert`, or empty for every mode.
+fn key_scope(action: &str) -> &str {
+    match action.split_once(':') {
+        Some(("macro", _)) | None => "",
+        Some((scope, _)) => scope,
     }
 }
 
+/// Parses a space-separated key sequence such as `g g` or `Ctrl+x Ctrl+e`.
+fn parse_key_chord(s: &str) -> Option<KeyChord> {
+    let chord: Option<KeyChord> = s.split_whitespace().map(parse_key_binding).collect();
+    chord.filter(|keys| !keys.is_empty())
+}
+
 fn parse_key_binding(s: &str) -> Option<(KeyCode, KeyModifiers)> {
     let parts: Vec<&str> = s.split('+').map(|p| p.trim()).collect();
     let mut modifiers = KeyModifiers::NONE;
@@ -882,6 +2196,7 @@ fn parse_key_binding(s: &str) -> Option<(KeyCode, KeyModifiers)> {
                 "insert" => KeyCode::Insert,
                 "delete" => KeyCode::Delete,
                 "esc" => KeyCode::Esc,
+                "backtab" => KeyCode::BackTab,
                 "null" => KeyCode::Null,
                 _ => return None,
             };
@@ -892,6 +2207,29 @@ fn parse_key_binding(s: &str) -> Option<(KeyCode, KeyModifiers)> {
     }
 }
 
+fn get_key_chord_str(chord: &[(KeyCode, KeyModifiers)]) -> String {
+    chord.iter().map(get_key_binding_str).collect::<Vec<_>>().join(" ")
+}
+
+/// Whether two key sequences are the same. Shift is ignored on characters, since terminals
+/// disagree on whether `G` arrives with it, and Shift+Tab is the same key as BackTab.
+fn chords_match(a: &[(KeyCode, KeyModifiers)], b: &[(KeyCode, KeyModifiers)]) -> bool {
+    let normalize = |&(code, modifiers): &(KeyCode, KeyModifiers)| match code {
+        KeyCode::Char(_) | KeyCode::BackTab => (code, modifiers - KeyModifiers::SHIFT),
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+            (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT)
+        },
+        _ => (code, modifiers),
+    };
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| normalize(x) == normalize(y))
+}
+
+/// Whether one chord equals or starts with the other.
+fn chords_overlap(a: &[(KeyCode, KeyModifiers)], b: &[(KeyCode, KeyModifiers)]) -> bool {
+    let len = a.len().min(b.len());
+    chords_match(&a[..len], &b[..len])
+}
+
 fn get_key_binding_str(binding: &(KeyCode, KeyModifiers)) -> String {
     let (key_code, modifier) = binding;
     let mut parts = Vec::new();
@@ -924,6 +2262,7 @@ fn key_code_to_string(key_code: KeyCode) -> String {
         KeyCode::Insert => "Insert".to_string(),
         KeyCode::Delete => "Delete".to_string(),
         KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "BackTab".to_string(),
         KeyCode::Esc => "Esc".to_string(),
         KeyCode::F(n) => format!("F{}", n),
         _ => "Unknown".to_string(),
@@ -945,7 +2284,7 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &mut AppState) {
         .margin(1)
         .constraints([
             Constraint::Length(3),
-            Constraint::Length(5),
+            Constraint::Length(if app.settings.show_suggestions { 5 } else { 0 }),
             Constraint::Min(3),
             Constraint::Length(1),
         ])
@@ -1004,21 +2343,31 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &mut AppState) {
         Text::from(line)
     };
 
-    let search_input = Paragraph::new(input_text).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(D_CYAN))
-            .title("Search".bold()),
-    );
+    let mut search_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(D_CYAN))
+        .title("Search".bold());
+    if let Some(daily) = &app.daily_skin {
+        search_block = search_block.title(
+            Title::from(Span::styled(
+                format!(" Skin of the day: {} ", daily),
+                Style::default().fg(D_PINK),
+            ))
+            .alignment(Alignment::Right),
+        );
+    }
+    let search_input = Paragraph::new(input_text).block(search_block);
 
     f.render_widget(search_input, chunks[0]);
+    app.layout.search = chunks[0];
 
     let inner_area = chunks[0].inner(&Margin { horizontal: 1, vertical: 1 });
     let cursor_x = inner_area.x + app.input.len() as u16;
     let cursor_y = inner_area.y;
     f.set_cursor(cursor_x, cursor_y);
 
+    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
     let suggestions: Vec<ListItem> = app
         .suggestion_list
         .iter()
@@ -1041,30 +2390,16 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &mut AppState) {
             };
 
             let last_part = app.input.split_whitespace().last().unwrap_or("").to_lowercase();
-            let mut spans = Vec::new();
-            let suggestion_lower = t.to_lowercase();
-
-            if suggestion_lower.contains(&last_part) && !last_part.is_empty() {
-                let start_idx = suggestion_lower.find(&last_part).unwrap_or(0);
-                let end_idx = start_idx + last_part.len();
-                let before_match = &t[..start_idx];
-                let matched_part = &t[start_idx..end_idx];
-                let after_match = &t[end_idx..];
-
-                if !before_match.is_empty() {
-                    spans.push(Span::styled(before_match, style));
-                }
-                spans.push(Span::styled(
-                    matched_part,
-                    style.fg(D_PINK).add_modifier(Modifier::UNDERLINED),
-                ));
-                if !after_match.is_empty() {
-                    spans.push(Span::styled(after_match, style));
-                }
-            } else {
-                spans.push(Span::styled(t, style));
+            let indices = matcher
+                .fuzzy_indices(&t.to_lowercase(), &last_part)
+                .map(|(_, indices)| indices)
+                .unwrap_or_default();
+            let mut spans = highlight_chars(t, &indices, style).spans;
+            if let Some(canonical) = &term_info.alias_of {
+                spans.push(Span::styled(format!(" → {}", canonical), style));
             }
 
+            let t = term_info.alias_of.as_ref().unwrap_or(t);
             let count = if *t == "favorite" {
                 app.favorites.len()
             } else {
@@ -1097,20 +2432,44 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &mut AppState) {
         )
         .highlight_style(Style::default().bg(D_BACKGROUND));
 
-    f.render_stateful_widget(suggestion_list, chunks[1], &mut list_state);
+    if app.settings.show_suggestions {
+        f.render_stateful_widget(suggestion_list, chunks[1], &mut list_state);
+        app.layout.suggestions = chunks[1].inner(&Margin { horizontal: 1, vertical: 1 });
+        app.layout.suggestion_offset = list_state.offset();
+    } else {
+        app.layout.suggestions = Rect::default();
+    }
 
-    let (table_area, detail_area) = if app.show_detail {
+    let main_area = if app.show_facets {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .constraints([Constraint::Length(26), Constraint::Min(0)])
             .split(chunks[2]);
-        (chunks[0], Some(chunks[1]))
+        render_facet_panel(f, app, chunks[0]);
+        chunks[1]
     } else {
+        app.layout.facets = Rect::default();
+        chunks[2]
+    };
+
+    let stacked = main_area.width < app.settings.stack_below_width;
+    app.layout.split = main_area;
+    app.layout.stacked = stacked;
+    let (table_area, detail_area) = if app.show_detail {
+        let table_percent = app.settings.table_percent.clamp(20, 80);
         let chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(100)])
-            .split(chunks[2]);
-        (chunks[0], None)
+            .direction(if stacked { Direction::Vertical } else { Direction::Horizontal })
+            .constraints([
+                Constraint::Percentage(table_percent),
+                Constraint::Percentage(100 - table_percent),
+            ])
+            .split(main_area);
+        app.layout.divider =
+            if stacked { Rect { height: 1, ..chunks[1] } } else { Rect { width: 1, ..chunks[1] } };
+        (chunks[0], Some(chunks[1]))
+    } else {
+        app.layout.divider = Rect::default();
+        (main_area, None)
     };
 
     render_table_view(f, app, table_area);
@@ -1118,18 +2477,44 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &mut AppState) {
         render_detail_panel(f, app, detail_area);
     }
 
-    let status = Line::from(vec![
-        Span::styled(" esc ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
-        Span::styled(" exit  ", Style::default().fg(D_FOREGROUND)),
-        Span::styled(" ctrl+h ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
-        Span::styled(" help  ", Style::default().fg(D_FOREGROUND)),
-        Span::styled(" tab ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
-        Span::styled(" cycle suggestions  ", Style::default().fg(D_FOREGROUND)),
-        Span::styled(" ► ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
-        Span::styled(" accept ", Style::default().fg(D_FOREGROUND)),
-        Span::styled(" ▲/▼ ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
-        Span::styled(" select  ", Style::default().fg(D_FOREGROUND)),
-    ]);
+    let toast = app.toast.as_ref().filter(|(_, shown)| shown.elapsed() < TOAST_DURATION);
+    let mut status = if let Some((message, _)) = toast {
+        Line::from(Span::styled(
+            format!(" ✓ {} ", message),
+            Style::default().bg(D_GREEN).fg(Color::Black).bold(),
+        ))
+    } else if let Some(message) = &app.status_message {
+        Line::from(Span::styled(message.clone(), Style::default().fg(D_FOREGROUND)))
+    } else {
+        Line::from(vec![
+            Span::styled(" esc ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
+            Span::styled(" exit  ", Style::default().fg(D_FOREGROUND)),
+            Span::styled(" ctrl+h ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
+            Span::styled(" help  ", Style::default().fg(D_FOREGROUND)),
+            Span::styled(" tab ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
+            Span::styled(" cycle suggestions  ", Style::default().fg(D_FOREGROUND)),
+            Span::styled(" ► ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
+            Span::styled(" accept ", Style::default().fg(D_FOREGROUND)),
+            Span::styled(" ▲/▼ ", Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND)),
+            Span::styled(" select  ", Style::default().fg(D_FOREGROUND)),
+        ])
+    };
+    if app.settings.vim_mode {
+        let mode = match app.mode {
+            InputMode::Normal => " NORMAL ",
+            InputMode::Insert => " INSERT ",
+        };
+        let pending = get_key_chord_str(&app.pending_keys);
+        status.spans.insert(0, Span::styled(format!(" {} ", pending), Style::default().fg(D_PINK)));
+        status
+            .spans
+            .insert(0, Span::styled(mode, Style::default().bg(D_PINK).fg(Color::Black).bold()));
+    }
+    if app.recording.is_some() {
+        status
+            .spans
+            .insert(0, Span::styled(" REC ", Style::default().bg(D_RED).fg(Color::Black).bold()));
+    }
     let status_bar = Paragraph::new(status).style(Style::default()).alignment(Alignment::Center);
     f.render_widget(status_bar, chunks[3]);
 }
@@ -1156,6 +2541,14 @@ fn get_term_style(term: &str, all_terms: &HashMap<String, TermInfo>) -> Style {
 }
 
 fn render_table_view<B: Backend>(f: &mut Frame<B>, app: &mut AppState, area: Rect) {
+    // One result per line between the borders and the header row
+    app.set_items_per_page(area.height.saturating_sub(3) as usize);
+    app.layout.header.clear();
+    app.layout.tags.clear();
+    app.layout.rows = Rect::default();
+    app.layout.prev_page = Rect::default();
+    app.layout.next_page = Rect::default();
+
     if app.results.is_empty() {
         let block = Block::default()
             .borders(Borders::ALL)
@@ -1180,25 +2573,22 @@ fn render_table_view<B: Backend>(f: &mut Frame<B>, app: &mut AppState, area: Rec
         let table_area = table_chunks[0];
         let scrollbar_area = table_chunks[1];
 
-        let name_header = if app.sort_field == SortField::Name && app.sort_descending {
-            "Name ↓"
-        } else {
-            "Name"
-        };
-        let rarity_header = if app.sort_field == SortField::Rarity && app.sort_descending {
-            "Rarity ↓"
-        } else {
-            "Rarity"
-        };
-        let event_header = if app.sort_field == SortField::Event && app.sort_descending {
-            "Event ↓"
-        } else {
-            "Event"
-        };
-
-        let header = Row::new(vec![name_header, rarity_header, event_header, "Year", "Tags"])
+        let mut header_cells = vec![
+            sort_header("Name", SortField::Name, app),
+            sort_header("Rarity", SortField::Rarity, app),
+            sort_header("Event", SortField::Event, app),
+            sort_header("Year", SortField::Year, app),
+            "Tags".to_string(),
+        ];
+        if app.explain {
+            header_cells.push("Score".to_string());
+        }
+        let header = Row::new(header_cells)
             .style(Style::default().fg(D_YELLOW).add_modifier(Modifier::BOLD));
 
+        let query = app.search_query();
+        let terms = parse_query(&query).terms;
+        let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
         let rows: Vec<Row> = app.results[start..end]
             .iter()
             .map(|skin| {
@@ -1208,22 +2598,62 @@ fn render_table_view<B: Backend>(f: &mut Frame<B>, app: &mut AppState, area: Rec
                 }
 
                 let year = skin.year.map_or(String::from("N/A"), |y| y.to_string());
-                Row::new(vec![
-                    Line::from(Span::styled(&skin.name, Style::default().fg(D_CYAN))),
+                let name_indices = fuzzy_match_indices(&skin.name_lower, &terms, &matcher);
+                let event_indices = fuzzy_match_indices(&skin.event_lower, &terms, &matcher);
+                let mut cells = vec![
+                    highlight_chars(&skin.name, &name_indices, Style::default().fg(D_CYAN)),
                     Line::from(Span::styled(
                         &skin.rarity,
                         Style::default().fg(get_rarity_color(skin)),
                     )),
-                    Line::from(Span::styled(&skin.event, Style::default().fg(D_ORANGE))),
+                    highlight_chars(&skin.event, &event_indices, Style::default().fg(D_ORANGE)),
                     Line::from(Span::styled(year, Style::default().fg(D_GREEN))),
-                    Line::from(Span::styled(
-                        tags_display.join(", "),
-                        Style::default().fg(D_FOREGROUND),
-                    )),
-                ])
+                    highlight_tags(&tags_display, &terms, &matcher),
+                ];
+                if app.explain {
+                    let breakdown =
+                        explain_skin(skin, &query, &app.favorites, &app.ranking_profile);
+                    cells.push(Line::from(Span::styled(
+                        breakdown.total().to_string(),
+                        Style::default().fg(D_YELLOW),
+                    )));
+                }
+                Row::new(cells)
             })
             .collect();
 
+        let widths: &[Constraint] = if app.explain {
+            &[
+                Constraint::Percentage(22),
+                Constraint::Percentage(12),
+                Constraint::Percentage(22),
+                Constraint::Percentage(8),
+                Constraint::Percentage(24),
+                Constraint::Percentage(12),
+            ]
+        } else {
+            &[
+                Constraint::Percentage(25),
+                Constraint::Percentage(15),
+                Constraint::Percentage(25),
+                Constraint::Percentage(10),
+                Constraint::Percentage(25),
+            ]
+        };
+
+        let mut title = format!(
+            "Results: {} | Page {}/{} | {} - {} | Ranking: {}",
+            app.results.len(),
+            app.current_page + 1,
+            total_pages,
+            start + 1,
+            end,
+            app.ranking_profile.name
+        );
+        if let Some(correction) = &app.correction {
+            title.push_str(&format!(" | Showing results for \"{}\"", correction));
+        }
+
         let table = Table::new(rows)
             .header(header)
             .block(
@@ -1231,26 +2661,69 @@ fn render_table_view<B: Backend>(f: &mut Frame<B>, app: &mut AppState, area: Rec
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(Style::default().fg(D_CYAN))
-                    .title(format!(
-                        "Results: {} | Page {}/{} | {} - {}",
-                        app.results.len(),
-                        app.current_page + 1,
-                        total_pages,
-                        start + 1,
-                        end
-                    )),
+                    .title(title),
             )
-            .widths(&[
-                Constraint::Percentage(25),
-                Constraint::Percentage(15),
-                Constraint::Percentage(25),
-                Constraint::Percentage(10),
-                Constraint::Percentage(25),
-            ])
+            .widths(widths)
             .highlight_style(Style::default().bg(D_BACKGROUND).add_modifier(Modifier::BOLD));
 
         f.render_stateful_widget(table, table_area, &mut app.table_state);
 
+        // Record where the header cells, rows and tags landed for mouse clicks
+        let inner = table_area.inner(&Margin { horizontal: 1, vertical: 1 });
+        let columns = table_column_rects(inner, widths);
+        let fields = [
+            Some(SortField::Name),
+            Some(SortField::Rarity),
+            Some(SortField::Event),
+            Some(SortField::Year),
+        ];
+        app.layout.header = columns
+            .iter()
+            .enumerate()
+            .map(|(i, rect)| (Rect { height: 1, ..*rect }, fields.get(i).copied().flatten()))
+            .collect();
+        let row_offset = app.table_state.offset();
+        let visible_rows = (inner.height.saturating_sub(1) as usize).min(end - start - row_offset);
+        app.layout.rows = Rect { y: inner.y + 1, height: visible_rows as u16, ..inner };
+        app.layout.row_offset = row_offset;
+        if let Some(tags_column) = columns.get(4) {
+            for (i, skin) in app.results[start + row_offset..].iter().take(visible_rows).enumerate()
+            {
+                let mut tags = skin.tags.clone();
+                if app.favorites.contains(&skin.name) {
+                    tags.push("favorite".to_string());
+                }
+                let mut x = tags_column.x;
+                for tag in tags {
+                    let width = tag.chars().count() as u16;
+                    if x + width > tags_column.right() {
+                        break;
+                    }
+                    let rect = Rect::new(x, app.layout.rows.y + i as u16, width, 1);
+                    app.layout.tags.push((rect, tag));
+                    x += width + 2; // ", " separator
+                }
+            }
+        }
+
+        // Page buttons on the bottom border
+        let bottom = table_area.bottom().saturating_sub(1);
+        let button_style = Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND);
+        if app.current_page > 0 && table_area.width > 12 {
+            app.layout.prev_page = Rect::new(table_area.x + 2, bottom, 8, 1);
+            f.render_widget(
+                Paragraph::new(Span::styled(" ◄ prev ", button_style)),
+                app.layout.prev_page,
+            );
+        }
+        if app.current_page + 1 < total_pages && table_area.width > 22 {
+            app.layout.next_page = Rect::new(table_area.right() - 10, bottom, 8, 1);
+            f.render_widget(
+                Paragraph::new(Span::styled(" next ► ", button_style)),
+                app.layout.next_page,
+            );
+        }
+
         let selected = app.table_state.selected().unwrap_or(0);
         let absolute_position = start + selected;
         let mut scrollbar_state = ScrollbarState::default()
@@ -1269,7 +2742,195 @@ fn render_table_view<B: Backend>(f: &mut Frame<B>, app: &mut AppState, area: Rec
     }
 }
 
-fn render_detail_panel<B: Backend>(f: &mut Frame<B>, app: &AppState, area: Rect) {
+/// The cells a `Table` with these widths lays out in `inner`, mirroring ratatui's column split
+/// (one column of spacing, no highlight symbol).
+fn table_column_rects(inner: Rect, widths: &[Constraint]) -> Vec<Rect> {
+    let mut constraints = vec![Constraint::Length(0)];
+    for width in widths {
+        constraints.push(*width);
+        constraints.push(Constraint::Length(1));
+    }
+    constraints.pop();
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(inner)
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .copied()
+        .collect()
+}
+
+/// A column title with ▲/▼ when the results are sorted by it, numbered by priority once more
+/// than one column is sorted.
+fn sort_header(title: &str, field: SortField, app: &AppState) -> String {
+    if app.is_default_sort() && !app.input.trim().is_empty() {
+        return title.to_string();
+    }
+    match app.sort_keys.iter().position(|key| key.field == field) {
+        Some(i) => {
+            let arrow = if app.sort_keys[i].descending { "▼" } else { "▲" };
+            if app.sort_keys.len() > 1 {
+                format!("{} {}{}", title, arrow, i + 1)
+            } else {
+                format!("{} {}", title, arrow)
+            }
+        },
+        None => title.to_string(),
+    }
+}
+
+fn render_statistics<B: Backend>(f: &mut Frame<B>, stats: &SkinStats, scope: &str) {
+    let modal_area = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(5)
+        .constraints([Constraint::Percentage(100)].as_ref())
+        .split(f.size())[0];
+    f.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .title(format!(
+            "Statistics - {}: {} skins, {} favorites (Tab to switch scope, Esc to close)",
+            scope, stats.total, stats.favorites
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(D_CYAN));
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(12), Constraint::Min(5)])
+        .split(inner);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 3), Constraint::Ratio(1, 3), Constraint::Ratio(1, 3)])
+        .split(rows[0]);
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(rows[1]);
+
+    let charts = [
+        ("By rarity", &stats.by_rarity, top[0], Direction::Vertical),
+        ("By year", &stats.by_year, top[1], Direction::Vertical),
+        ("Favorites by rarity", &stats.favorites_by_rarity, top[2], Direction::Vertical),
+        ("By event", &stats.by_event, bottom[0], Direction::Horizontal),
+        ("Tag frequency", &stats.by_tag, bottom[1], Direction::Horizontal),
+    ];
+    for (title, data, area, direction) in charts {
+        render_stat_chart(f, title, data, area, direction);
+    }
+}
+
+/// Vertical charts size their bars to fit every entry; horizontal ones show as many
+/// of the most common entries as there are rows.
+fn render_stat_chart<B: Backend>(
+    f: &mut Frame<B>,
+    title: &str,
+    data: &[(String, u64)],
+    area: Rect,
+    direction: Direction,
+) {
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let (shown, bar_width, bar_gap) = match direction {
+        Direction::Vertical => {
+            let count = data.len().max(1);
+            let width = ((inner_width + 1) / count).saturating_sub(1).clamp(1, 10);
+            (data.len(), width as u16, 1)
+        },
+        Direction::Horizontal => (inner_height, 1, 0),
+    };
+    let bars: Vec<Bar> = data
+        .iter()
+        .take(shown)
+        .map(|(label, value)| {
+            let bar = Bar::default().value(*value);
+            match direction {
+                Direction::Vertical => bar.label(Line::from(label.as_str())),
+                // Horizontal bars have no label slot, so the label rides along with the value
+                Direction::Horizontal => bar.text_value(format!("{} {}", value, label)),
+            }
+        })
+        .collect();
+    let title = if data.len() > bars.len() {
+        format!("{} (top {} of {})", title, bars.len(), data.len())
+    } else {
+        title.to_string()
+    };
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(D_CYAN)),
+        )
+        .direction(direction)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(bar_gap)
+        .bar_style(Style::default().fg(D_PINK))
+        .value_style(Style::default().fg(D_BACKGROUND).bg(D_PINK))
+        .label_style(Style::default().fg(D_FOREGROUND));
+    f.render_widget(chart, area);
+}
+
+fn render_facet_panel<B: Backend>(f: &mut Frame<B>, app: &mut AppState, area: Rect) {
+    let rows = app.facet_rows();
+    let items: Vec<ListItem> = rows
+        .iter()
+        .enumerate()
+        .map(|(r, row)| match row {
+            None => {
+                // A heading is always followed by the first facet of its group
+                let field = rows.get(r + 1).copied().flatten().map_or("", |i| app.facets[i].0);
+                let mut heading = field.to_string();
+                if let Some(first) = heading.get_mut(0..1) {
+                    first.make_ascii_uppercase();
+                }
+                ListItem::new(Line::from(Span::styled(
+                    heading,
+                    Style::default().fg(D_YELLOW).add_modifier(Modifier::BOLD),
+                )))
+            },
+            Some(i) => {
+                let (_, value) = &app.facets[*i];
+                let checkbox = if app.is_facet_checked(*i) { "[x] " } else { "[ ] " };
+                let count = app.facet_counts.get(*i).copied().unwrap_or(0);
+                let style = if count == 0 {
+                    Style::default().fg(Color::DarkGray)
+                } else {
+                    Style::default().fg(D_FOREGROUND)
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(checkbox, Style::default().fg(D_CYAN)),
+                    Span::styled(value.clone(), style),
+                    Span::styled(format!(" ({})", count), style),
+                ]))
+            },
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title("Filters")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(D_CYAN)),
+        )
+        .highlight_style(Style::default().bg(D_BACKGROUND).add_modifier(Modifier::BOLD));
+    f.render_stateful_widget(list, area, &mut app.facet_state);
+    app.layout.facets = area.inner(&Margin { horizontal: 1, vertical: 1 });
+    app.layout.facet_offset = app.facet_state.offset();
+}
+
+fn render_detail_panel<B: Backend>(f: &mut Frame<B>, app: &mut AppState, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
@@ -1279,2061 +2940,210 @@ fn render_detail_panel<B: Backend>(f: &mut Frame<B>, app: &AppState, area: Rect)
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
+    app.layout.chips.clear();
 
-    if let Some(selected) = app.table_state.selected() {
-        let absolute_index = app.current_page * app.items_per_page + selected;
-        if let Some(skin) = app.results.get(absolute_index) {
-            let mut tags = skin.tags.clone();
-            if app.favorites.contains(&skin.name) {
-                tags.push("favorite".to_string());
-            }
-
-            let details = vec![
-                Line::from(vec![
-                    Span::styled("Name: ", Style::default().fg(D_YELLOW)),
-                    Span::styled(&skin.name, Style::default().fg(D_YELLOW)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Rarity: ", Style::default().fg(D_YELLOW)),
-                    Span::styled(&skin.rarity, Style::default().fg(get_rarity_color(skin))),
-                ]),
-                Line::from(vec![
-                    Span::styled("Event: ", Style::default().fg(D_YELLOW)),
-                    Span::styled(&skin.event, Style::default().fg(D_YELLOW)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Year: ", Style::default().fg(D_YELLOW)),
-                    Span::styled(
-                        skin.year.map_or(String::from("N/A"), |y| y.to_string()),
-                        Style::default().fg(D_YELLOW),
-                    ),
-                ]),
-                Line::from(
-                    std::iter::once(Span::styled("Tags: ", Style::default().fg(D_YELLOW)))
-                        .chain(render_tags(&tags))
-                        .collect::<Vec<_>>(),
-                ),
-            ];
-
-            let details_paragraph = Paragraph::new(details)
-                .block(Block::default().borders(Borders::NONE))
-                .alignment(Alignment::Left)
-                .wrap(Wrap { trim: true });
-            f.render_widget(details_paragraph, inner_area);
+    let Some(selected) = app.table_state.selected() else {
+        return;
+    };
+    let absolute_index = app.current_page * app.items_per_page + selected;
+    let Some(skin) = app.results.get(absolute_index).cloned() else {
+        return;
+    };
+    let chips = app.detail_chips();
+    let focus = app.chip_focus.filter(|&i| i < chips.len());
+    let chip_style = |i: usize, base: Style| {
+        if focus == Some(i) {
+            Style::default().bg(D_PINK).fg(Color::Black).add_modifier(Modifier::BOLD)
+        } else {
+            base
         }
+    };
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(D_YELLOW));
+    let rarity_badge = Style::default().bg(get_rarity_color(&skin)).fg(Color::Black).bold();
+    let tag_chip = Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND).add_modifier(Modifier::BOLD);
+
+    let mut details = vec![
+        Line::from(vec![
+            label("Name: "),
+            Span::styled(skin.name.clone(), Style::default().fg(D_YELLOW)),
+        ]),
+        Line::from(vec![
+            label("Rarity: "),
+            Span::styled(format!(" {} ", skin.rarity), chip_style(0, rarity_badge)),
+        ]),
+        Line::from(vec![
+            label("Event: "),
+            Span::styled(skin.event.clone(), Style::default().fg(D_YELLOW)),
+        ]),
+        Line::from(vec![
+            label("Year: "),
+            Span::styled(
+                skin.year.map_or(String::from("N/A"), |y| y.to_string()),
+                Style::default().fg(D_YELLOW),
+            ),
+        ]),
+    ];
+    let rarity_width = skin.rarity.chars().count() as u16 + 2;
+    if inner_area.height > 1 && inner_area.width >= 8 + rarity_width {
+        let rect = Rect::new(inner_area.x + 8, inner_area.y + 1, rarity_width, 1);
+        app.layout.chips.push((rect, chips[0].clone()));
     }
-}
 
-fn render_tags(tags: &[String]) -> Vec<Span> {
-    let mut spans = Vec::new();
-    for tag in tags {
-        spans.push(Span::styled(
-            format!(" {} ", tag),
-            Style::default().bg(D_BACKGROUND).fg(D_FOREGROUND).add_modifier(Modifier::BOLD),
-        ));
-        spans.push(Span::raw(" "));
-    }
-    if !spans.is_empty() {
-        spans.pop();
+    // Tag chips flow onto as many lines as they need, indented under "Tags: "
+    let mut tags = skin.tags.clone();
+    if app.favorites.contains(&skin.name) {
+        tags.push("favorite".to_string());
     }
-    spans
-}
-
-fn load_all_terms(skins: &[Skin]) -> HashMap<String, TermInfo> {
-    let mut terms: HashMap<String, TermInfo> = HashMap::new();
-    for skin in skins {
-        for word in skin.name_lower.split_whitespace() {
-            terms.entry(word.to_string()).or_default().is_name = true;
+    let indent = 6;
+    let mut line = vec![label("Tags: ")];
+    let mut x = indent;
+    for (i, tag) in tags.iter().enumerate() {
+        let width = tag.chars().count() as u16 + 2;
+        if x > indent && x + 1 + width > inner_area.width {
+            details.push(Line::from(std::mem::take(&mut line)));
+            line.push(Span::raw(" ".repeat(indent as usize)));
+            x = indent;
         }
-        for word in skin.event_lower.split_whitespace() {
-            terms.entry(word.to_string()).or_default().is_event = true;
+        if x > indent {
+            line.push(Span::raw(" "));
+            x += 1;
         }
-        terms.entry(skin.rarity_lower.clone()).or_default().is_rarity = true;
-        for tag in &skin.tags_lower {
-            terms.entry(tag.clone()).or_default().is_tag = true;
-        }
-        if !skin.year_str.is_empty() {
-            terms.entry(skin.year_str.clone()).or_default().is_year = true;
+        let y = details.len() as u16;
+        if y < inner_area.height && x + width <= inner_area.width {
+            let rect = Rect::new(inner_area.x + x, inner_area.y + y, width, 1);
+            app.layout.chips.push((rect, chips[i + 1].clone()));
         }
+        line.push(Span::styled(format!(" {} ", tag), chip_style(i + 1, tag_chip)));
+        x += width;
     }
-    terms
+    details.push(Line::from(line));
+
+    if app.explain {
+        details.extend(render_score_breakdown(&explain_skin(
+            &skin,
+            &app.search_query(),
+            &app.favorites,
+            &app.ranking_profile,
+        )));
+    }
+
+    // Not wrapped, so the chips stay where their click areas were recorded
+    let details_paragraph = Paragraph::new(details)
+        .block(Block::default().borders(Borders::NONE))
+        .alignment(Alignment::Left);
+    f.render_widget(details_paragraph, inner_area);
 }
 
-fn load_skins() -> Vec<Skin> {
-    let mut skins = vec![
-        // Valentine Case
-        Skin {
-            name: "Cupid".to_string(),
-            name_lower: "cupid".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Valentine Case".to_string(),
-            event_lower: "valentine case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Rainbow Periastron".to_string(),
-            name_lower: "rainbow periastron".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Valentine Case (Exquisite)".to_string(),
-            event_lower: "valentine case (exquisite)".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Crimson Periastron".to_string(),
-            name_lower: "crimson periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Valentine Case".to_string(),
-            event_lower: "valentine case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Heartsong".to_string(),
-            name_lower: "heartsong".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Valentine Case".to_string(),
-            event_lower: "valentine case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Ivory Periastron".to_string(),
-            name_lower: "ivory periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Valentine Case (Exquisite)".to_string(),
-            event_lower: "valentine case (exquisite)".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Diamond".to_string(),
-            name_lower: "diamond".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Valentine Case (Exquisite)".to_string(),
-            event_lower: "valentine case (exquisite)".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Epicredness".to_string(),
-            name_lower: "epicredness".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Valentine Case (Exquisite)".to_string(),
-            event_lower: "valentine case (exquisite)".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Birthday Case
-        Skin {
-            name: "Ghostly".to_string(),
-            name_lower: "ghostly".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Birthday Case".to_string(),
-            event_lower: "birthday case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Hellfire".to_string(),
-            name_lower: "hellfire".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Birthday Case".to_string(),
-            event_lower: "birthday case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Surge".to_string(),
-            name_lower: "surge".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Birthday Case (Exquisite)".to_string(),
-            event_lower: "birthday case (exquisite)".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Epicblueness".to_string(),
-            name_lower: "epicblueness".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Birthday Case (Exquisite)".to_string(),
-            event_lower: "birthday case (exquisite)".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Golden".to_string(),
-            name_lower: "golden".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Birthday Case (Exquisite)".to_string(),
-            event_lower: "birthday case (exquisite)".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Grimgold Periastron".to_string(),
-            name_lower: "grimgold periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Birthday Case".to_string(),
-            event_lower: "birthday case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string(), "popular".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Easter Case
-        Skin {
-            name: "Spring Growth".to_string(),
-            name_lower: "spring growth".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Easter Case".to_string(),
-            event_lower: "easter case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Amethyst Periastron".to_string(),
-            name_lower: "amethyst periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Easter Case".to_string(),
-            event_lower: "easter case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Bunny".to_string(),
-            name_lower: "bunny".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Easter Case".to_string(),
-            event_lower: "easter case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Guitar".to_string(),
-            name_lower: "guitar".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Easter Case".to_string(),
-            event_lower: "easter case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Joyful Periastron".to_string(),
-            name_lower: "joyful periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Easter Case".to_string(),
-            event_lower: "easter case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Noir Periastron".to_string(),
-            name_lower: "noir periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Easter Case".to_string(),
-            event_lower: "easter case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Summer Case
-        Skin {
-            name: "Midsummer".to_string(),
-            name_lower: "midsummer".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Mystic".to_string(),
-            name_lower: "mystic".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "popular".to_string()],
-            tags_lower: vec!["case".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Void Lord".to_string(),
-            name_lower: "void lord".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "popular".to_string()],
-            tags_lower: vec!["case".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Warlord".to_string(),
-            name_lower: "warlord".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Cythrex".to_string(),
-            name_lower: "cythrex".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Dog".to_string(),
-            name_lower: "dog".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "popular".to_string()],
-            tags_lower: vec!["case".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Fire Wyvern".to_string(),
-            name_lower: "fire wyvern".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Ghostfire".to_string(),
-            name_lower: "ghostfire".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Inscription".to_string(),
-            name_lower: "inscription".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Mummy".to_string(),
-            name_lower: "mummy".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Retrowave".to_string(),
-            name_lower: "retrowave".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Shikai".to_string(),
-            name_lower: "shikai".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Summer Case".to_string(),
-            event_lower: "summer case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Halloween Case
-        Skin {
-            name: "All Hallow's".to_string(),
-            name_lower: "all hallow's".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Halloween Case".to_string(),
-            event_lower: "halloween case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "popular".to_string()],
-            tags_lower: vec!["case".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Anansi".to_string(),
-            name_lower: "anansi".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Halloween Case".to_string(),
-            event_lower: "halloween case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Dusekkar".to_string(),
-            name_lower: "dusekkar".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Halloween Case".to_string(),
-            event_lower: "halloween case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "popular".to_string()],
-            tags_lower: vec!["case".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Count".to_string(),
-            name_lower: "count".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Halloween Case".to_string(),
-            event_lower: "halloween case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Dracula".to_string(),
-            name_lower: "dracula".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Halloween Case".to_string(),
-            event_lower: "halloween case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Hallowing".to_string(),
-            name_lower: "hallowing".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Halloween Case".to_string(),
-            event_lower: "halloween case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Orange Energy".to_string(),
-            name_lower: "orange energy".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Halloween Case".to_string(),
-            event_lower: "halloween case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "energy".to_string()],
-            tags_lower: vec!["case".to_string(), "energy".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Pumpkin".to_string(),
-            name_lower: "pumpkin".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Halloween Case".to_string(),
-            event_lower: "halloween case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Christmas Case
-        Skin {
-            name: "Evergreen".to_string(),
-            name_lower: "evergreen".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Christmas Case".to_string(),
-            event_lower: "christmas case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Icycle".to_string(),
-            name_lower: "icycle".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Christmas Case".to_string(),
-            event_lower: "christmas case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Santa".to_string(),
-            name_lower: "santa".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Christmas Case".to_string(),
-            event_lower: "christmas case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Candy Energy".to_string(),
-            name_lower: "candy energy".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Christmas Case".to_string(),
-            event_lower: "christmas case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "energy".to_string()],
-            tags_lower: vec!["case".to_string(), "energy".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Festive Periastron".to_string(),
-            name_lower: "festive periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Christmas Case".to_string(),
-            event_lower: "christmas case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Snowflake".to_string(),
-            name_lower: "snowflake".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Christmas Case".to_string(),
-            event_lower: "christmas case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Snowman".to_string(),
-            name_lower: "snowman".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Christmas Case".to_string(),
-            event_lower: "christmas case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Easter Event
-        Skin {
-            name: "Azurite".to_string(),
-            name_lower: "azurite".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Easter Event".to_string(),
-            event_lower: "easter event".to_string(),
-            year: Some(2022),
-            year_str: "2022".to_string(),
-            tags: vec!["event".to_string(), "popular".to_string()],
-            tags_lower: vec!["event".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Corrupted".to_string(),
-            name_lower: "corrupted".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Easter Event".to_string(),
-            event_lower: "easter event".to_string(),
-            year: Some(2023),
-            year_str: "2023".to_string(),
-            tags: vec!["event".to_string(), "popular".to_string()],
-            tags_lower: vec!["event".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Sun Slayer".to_string(),
-            name_lower: "sun slayer".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Easter Event".to_string(),
-            event_lower: "easter event".to_string(),
-            year: Some(2024),
-            year_str: "2024".to_string(),
-            tags: vec!["event".to_string()],
-            tags_lower: vec!["event".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Summer Bundle
-        Skin {
-            name: "Cartoony Rainbow".to_string(),
-            name_lower: "cartoony rainbow".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Summer Bundle".to_string(),
-            event_lower: "summer bundle".to_string(),
-            year: Some(2023),
-            year_str: "2023".to_string(),
-            tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Cyberlight".to_string(),
-            name_lower: "cyberlight".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Summer Bundle".to_string(),
-            event_lower: "summer bundle".to_string(),
-            year: Some(2023),
-            year_str: "2023".to_string(),
-            tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Frostburn".to_string(),
-            name_lower: "frostburn".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Summer Bundle".to_string(),
-            event_lower: "summer bundle".to_string(),
-            year: Some(2023),
-            year_str: "2023".to_string(),
-            tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Inferno Angel".to_string(),
-            name_lower: "inferno angel".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Summer Bundle".to_string(),
-            event_lower: "summer bundle".to_string(),
-            year: Some(2023),
-            year_str: "2023".to_string(),
-            tags: vec!["bundle".to_string(), "popular".to_string()],
-            tags_lower: vec!["bundle".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Azure Dragon".to_string(),
-            name_lower: "azure dragon".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Summer Bundle".to_string(),
-            event_lower: "summer bundle".to_string(),
-            year: Some(2024),
-            year_str: "2024".to_string(),
-            tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Darkness".to_string(),
-            name_lower: "darkness".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Summer Bundle".to_string(),
-            event_lower: "summer bundle".to_string(),
-            year: Some(2024),
-            year_str: "2024".to_string(),
-            tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Vilethorn".to_string(),
-            name_lower: "vilethorn".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Summer Bundle".to_string(),
-            event_lower: "summer bundle".to_string(),
-            year: Some(2024),
-            year_str: "2024".to_string(),
-            tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Winged".to_string(),
-            name_lower: "winged".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Summer Bundle".to_string(),
-            event_lower: "summer bundle".to_string(),
-            year: Some(2024),
-            year_str: "2024".to_string(),
-            tags: vec!["bundle".to_string(), "popular".to_string()],
-            tags_lower: vec!["bundle".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Valentine Bundle
-        Skin {
-            name: "Cupid's Revenge".to_string(),
-            name_lower: "cupid's revenge".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Valentine Bundle".to_string(),
-            event_lower: "valentine bundle".to_string(),
-            year: Some(2025),
-            year_str: "2025".to_string(),
-            tags: vec!["bundle".to_string()],
-            tags_lower: vec!["bundle".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Love Scepter".to_string(),
-            name_lower: "love scepter".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Valentine Bundle".to_string(),
-            event_lower: "valentine bundle".to_string(),
-            year: Some(2025),
-            year_str: "2025".to_string(),
-            tags: vec!["bundle".to_string(), "popular".to_string()],
-            tags_lower: vec!["bundle".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Wicked Rose".to_string(),
-            name_lower: "wicked rose".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Valentine Bundle".to_string(),
-            event_lower: "valentine bundle".to_string(),
-            year: Some(2025),
-            year_str: "2025".to_string(),
-            tags: vec!["bundle".to_string(), "popular".to_string()],
-            tags_lower: vec!["bundle".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Christmas Event
-        Skin {
-            name: "Redmaster".to_string(),
-            name_lower: "redmaster".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Christmas Event".to_string(),
-            event_lower: "christmas event".to_string(),
-            year: Some(2022),
-            year_str: "2022".to_string(),
-            tags: vec!["event".to_string(), "rare".to_string()],
-            tags_lower: vec!["event".to_string(), "rare".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Yellowflame".to_string(),
-            name_lower: "yellowflame".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Christmas Event".to_string(),
-            event_lower: "christmas event".to_string(),
-            year: Some(2022),
-            year_str: "2022".to_string(),
-            tags: vec!["event".to_string(), "rare".to_string()],
-            tags_lower: vec!["event".to_string(), "rare".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Goldenrod".to_string(),
-            name_lower: "goldenrod".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Christmas Event".to_string(),
-            event_lower: "christmas event".to_string(),
-            year: Some(2022),
-            year_str: "2022".to_string(),
-            tags: vec!["event".to_string(), "rare".to_string()],
-            tags_lower: vec!["event".to_string(), "rare".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Whisper".to_string(),
-            name_lower: "whisper".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Christmas Event".to_string(),
-            event_lower: "christmas event".to_string(),
-            year: Some(2022),
-            year_str: "2022".to_string(),
-            tags: vec!["event".to_string(), "rare".to_string()],
-            tags_lower: vec!["event".to_string(), "rare".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Gingerblade".to_string(),
-            name_lower: "gingerblade".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Christmas Event".to_string(),
-            event_lower: "christmas event".to_string(),
-            year: Some(2022),
-            year_str: "2022".to_string(),
-            tags: vec!["event".to_string(), "rare".to_string()],
-            tags_lower: vec!["event".to_string(), "rare".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Candy Cane".to_string(),
-            name_lower: "candy cane".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Christmas Event".to_string(),
-            event_lower: "christmas event".to_string(),
-            year: Some(2023),
-            year_str: "2023".to_string(),
-            tags: vec!["event".to_string()],
-            tags_lower: vec!["event".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Iceblade".to_string(),
-            name_lower: "iceblade".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Christmas Event".to_string(),
-            event_lower: "christmas event".to_string(),
-            year: Some(2024),
-            year_str: "2024".to_string(),
-            tags: vec!["event".to_string(), "popular".to_string()],
-            tags_lower: vec!["event".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Code Redeemed Skins (Teal)
-        Skin {
-            name: "Bubbles".to_string(),
-            name_lower: "bubbles".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Code".to_string(),
-            event_lower: "code".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["code".to_string(), "gamenight".to_string()],
-            tags_lower: vec!["code".to_string(), "gamenight".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Butter".to_string(),
-            name_lower: "butter".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Code".to_string(),
-            event_lower: "code".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["code".to_string(), "duped".to_string()],
-            tags_lower: vec!["code".to_string(), "duped".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Fireworks".to_string(),
-            name_lower: "fireworks".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Code".to_string(),
-            event_lower: "code".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["code".to_string()],
-            tags_lower: vec!["code".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Pearl".to_string(),
-            name_lower: "pearl".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Code".to_string(),
-            event_lower: "code".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["code".to_string(), "gamenight".to_string()],
-            tags_lower: vec!["code".to_string(), "gamenight".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Tin".to_string(),
-            name_lower: "tin".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Code".to_string(),
-            event_lower: "code".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["code".to_string(), "gamenight".to_string()],
-            tags_lower: vec!["code".to_string(), "gamenight".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Launch Skins (Teal)
-        Skin {
-            name: "Blastoff".to_string(),
-            name_lower: "blastoff".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Launch".to_string(),
-            event_lower: "launch".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["launch".to_string()],
-            tags_lower: vec!["launch".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Exquisite Case (Pinks & Reds)
-        Skin {
-            name: "Behemoth".to_string(),
-            name_lower: "behemoth".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Blizzard".to_string(),
-            name_lower: "blizzard".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string(), "popular".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Crescendo".to_string(),
-            name_lower: "crescendo".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Demon".to_string(),
-            name_lower: "demon".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Overseer".to_string(),
-            name_lower: "overseer".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Redcliff".to_string(),
-            name_lower: "redcliff".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Skeletal".to_string(),
-            name_lower: "skeletal".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Telamonster".to_string(),
-            name_lower: "telamonster".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Unseen".to_string(),
-            name_lower: "unseen".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Bombastic".to_string(),
-            name_lower: "bombastic".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Crimsonwrath".to_string(),
-            name_lower: "crimsonwrath".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Sanctum".to_string(),
-            name_lower: "sanctum".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Exquisite Case".to_string(),
-            event_lower: "exquisite case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "exquisite".to_string()],
-            tags_lower: vec!["case".to_string(), "exquisite".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Animal Case
-        Skin {
-            name: "Spider".to_string(),
-            name_lower: "spider".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Animal Case".to_string(),
-            event_lower: "animal case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Unicorn".to_string(),
-            name_lower: "unicorn".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Animal Case".to_string(),
-            event_lower: "animal case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Bacon".to_string(),
-            name_lower: "bacon".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Animal Case".to_string(),
-            event_lower: "animal case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Salmon".to_string(),
-            name_lower: "salmon".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Animal Case".to_string(),
-            event_lower: "animal case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "popular".to_string()],
-            tags_lower: vec!["case".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Shark".to_string(),
-            name_lower: "shark".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Animal Case".to_string(),
-            event_lower: "animal case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Slither".to_string(),
-            name_lower: "slither".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Animal Case".to_string(),
-            event_lower: "animal case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Camouflage Case
-        Skin {
-            name: "Dragon's Forge".to_string(),
-            name_lower: "dragon's forge".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Camouflage Case".to_string(),
-            event_lower: "camouflage case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Glacial".to_string(),
-            name_lower: "glacial".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Camouflage Case".to_string(),
-            event_lower: "camouflage case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Chartreuse Periastron".to_string(),
-            name_lower: "chartreuse periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Camouflage Case".to_string(),
-            event_lower: "camouflage case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Fallen".to_string(),
-            name_lower: "fallen".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Camouflage Case".to_string(),
-            event_lower: "camouflage case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Prehistoric".to_string(),
-            name_lower: "prehistoric".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Camouflage Case".to_string(),
-            event_lower: "camouflage case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Shadow".to_string(),
-            name_lower: "shadow".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Camouflage Case".to_string(),
-            event_lower: "camouflage case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Violet Energy".to_string(),
-            name_lower: "violet energy".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Camouflage Case".to_string(),
-            event_lower: "camouflage case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "energy".to_string()],
-            tags_lower: vec!["case".to_string(), "energy".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        // Future Case
-        Skin {
-            name: "Laser".to_string(),
-            name_lower: "laser".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Future Case".to_string(),
-            event_lower: "future case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Orinthian".to_string(),
-            name_lower: "orinthian".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Future Case".to_string(),
-            event_lower: "future case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Azure Periastron".to_string(),
-            name_lower: "azure periastron".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Future Case".to_string(),
-            event_lower: "future case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "periastron".to_string()],
-            tags_lower: vec!["case".to_string(), "periastron".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Celestial".to_string(),
-            name_lower: "celestial".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Future Case".to_string(),
-            event_lower: "future case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Galactic".to_string(),
-            name_lower: "galactic".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Future Case".to_string(),
-            event_lower: "future case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Green Energy".to_string(),
-            name_lower: "green energy".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Future Case".to_string(),
-            event_lower: "future case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string(), "energy".to_string()],
-            tags_lower: vec!["case".to_string(), "energy".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Motherboard".to_string(),
-            name_lower: "motherboard".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Future Case".to_string(),
-            event_lower: "future case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Omega".to_string(),
-            name_lower: "omega".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Future Case".to_string(),
-            event_lower: "future case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Material Case
-        Skin {
-            name: "Crystal".to_string(),
-            name_lower: "crystal".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Material Case".to_string(),
-            event_lower: "material case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Adurite".to_string(),
-            name_lower: "adurite".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Material Case".to_string(),
-            event_lower: "material case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Bluesteel".to_string(),
-            name_lower: "bluesteel".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Material Case".to_string(),
-            event_lower: "material case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Wooden".to_string(),
-            name_lower: "wooden".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Material Case".to_string(),
-            event_lower: "material case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Nature Case
-        Skin {
-            name: "Crystallised".to_string(),
-            name_lower: "crystallised".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Nature Case".to_string(),
-            event_lower: "nature case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Elven".to_string(),
-            name_lower: "elven".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Nature Case".to_string(),
-            event_lower: "nature case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Molten".to_string(),
-            name_lower: "molten".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Nature Case".to_string(),
-            event_lower: "nature case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Autumnal".to_string(),
-            name_lower: "autumnal".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Nature Case".to_string(),
-            event_lower: "nature case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Beach".to_string(),
-            name_lower: "beach".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Nature Case".to_string(),
-            event_lower: "nature case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Breeze".to_string(),
-            name_lower: "breeze".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Nature Case".to_string(),
-            event_lower: "nature case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Earth".to_string(),
-            name_lower: "earth".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Nature Case".to_string(),
-            event_lower: "nature case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Ocean".to_string(),
-            name_lower: "ocean".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Nature Case".to_string(),
-            event_lower: "nature case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Pattern Case
-        Skin {
-            name: "Monochrome".to_string(),
-            name_lower: "monochrome".to_string(),
-            rarity: "Pink".to_string(),
-            rarity_lower: "pink".to_string(),
-            event: "Pattern Case".to_string(),
-            event_lower: "pattern case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Relic".to_string(),
-            name_lower: "relic".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Pattern Case".to_string(),
-            event_lower: "pattern case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Sorcus".to_string(),
-            name_lower: "sorcus".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Pattern Case".to_string(),
-            event_lower: "pattern case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        // Refined Case
-        Skin {
-            name: "Archon".to_string(),
-            name_lower: "archon".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Breaker".to_string(),
-            name_lower: "breaker".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Divine".to_string(),
-            name_lower: "divine".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Enforcer".to_string(),
-            name_lower: "enforcer".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Frosted".to_string(),
-            name_lower: "frosted".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Hunter".to_string(),
-            name_lower: "hunter".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Neon".to_string(),
-            name_lower: "neon".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Pharaoh".to_string(),
-            name_lower: "pharaoh".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Skyward".to_string(),
-            name_lower: "skyward".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Steampunk".to_string(),
-            name_lower: "steampunk".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "Refined Case".to_string(),
-            event_lower: "refined case".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["case".to_string()],
-            tags_lower: vec!["case".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "No Dagger".to_string(),
-            name_lower: "no dagger".to_string(),
-            rarity: "Red".to_string(),
-            rarity_lower: "red".to_string(),
-            event: "April Fools".to_string(),
-            event_lower: "April Fools".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["all case".to_string(), "popular".to_string()],
-            tags_lower: vec!["all case".to_string(), "popular".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Whiteheart".to_string(),
-            name_lower: "whiteheart".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Worthy Individuals".to_string(),
-            event_lower: "worthy individuals".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["special".to_string(), "rare".to_string()],
-            tags_lower: vec!["special".to_string(), "rare".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
-        Skin {
-            name: "Darkheart".to_string(),
-            name_lower: "Darkheart".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Worthy Individuals".to_string(),
-            event_lower: "worthy individuals".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["special".to_string()],
-            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Banana".to_string(),
-            name_lower: "banana".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Pre-release Tester".to_string(),
-            event_lower: "pre-release tester".to_string(),
-            year: None,
-            year_str: "2021".to_string(),
-            tags: vec!["special".to_string()],
-            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Hammer".to_string(),
-            name_lower: "hammer".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Builder".to_string(),
-            event_lower: "builder".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["special".to_string()],
-            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Paintbrush".to_string(),
-            name_lower: "paintbrush".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Artist".to_string(),
-            event_lower: "artist".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["special".to_string()],
-            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "Riddling".to_string(),
-            name_lower: "riddling".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "Worthy Individuals".to_string(),
-            event_lower: "worthy individuals".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["special".to_string()],
-            tags_lower: vec!["special".to_string()].into_iter().map(|t| t.to_lowercase()).collect(),
-        },
-        Skin {
-            name: "VIP".to_string(),
-            name_lower: "vip".to_string(),
-            rarity: "Teal".to_string(),
-            rarity_lower: "teal".to_string(),
-            event: "VIP Players".to_string(),
-            event_lower: "vip players".to_string(),
-            year: None,
-            year_str: "".to_string(),
-            tags: vec!["special".to_string(), "VIP".to_string()],
-            tags_lower: vec!["special".to_string(), "vip".to_string()]
-                .into_iter()
-                .map(|t| t.to_lowercase())
-                .collect(),
-        },
+fn render_score_breakdown(breakdown: &ScoreBreakdown) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::default(),
+        Line::from(Span::styled(
+            format!("Score: {}", breakdown.total()),
+            Style::default().fg(D_YELLOW).add_modifier(Modifier::BOLD),
+        )),
     ];
-
-    // Post-process to populate derived fields
-    for skin in &mut skins {
-        skin.name_lower = skin.name.to_lowercase();
-        skin.rarity_lower = skin.rarity.to_lowercase();
-        skin.event_lower = skin.event.to_lowercase();
-        skin.year_str = skin.year.map(|y| y.to_string()).unwrap_or_default();
-        skin.tags_lower = skin.tags.iter().map(|t| t.to_lowercase()).collect();
+    let fields = breakdown.fields();
+    if fields.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  no search terms matched",
+            Style::default().fg(D_FOREGROUND).add_modifier(Modifier::ITALIC),
+        )));
     }
-
-    skins
+    for (field, points) in fields {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<9}", field), Style::default().fg(D_FOREGROUND)),
+            Span::styled(format!("{:+}", points), Style::default().fg(D_GREEN)),
+        ]));
+    }
+    lines
 }
 
-fn search_skins(
-    skins: &[Skin],
-    _name_map: &HashMap<String, usize>,
-    query: &str,
-    favorites: &HashSet<String>,
-) -> Vec<Skin> {
-    let mut filters: HashMap<String, String> = HashMap::new();
-    let mut terms: HashSet<String> = HashSet::new();
-    let mut regex: Option<Regex> = None;
-
-    // Parse query
-    let parts: Vec<&str> = query.split_whitespace().collect();
-    for part in parts {
-        if part.contains(':') {
-            let (field, value) = part.split_once(':').unwrap();
-            filters.insert(field.to_lowercase(), value.to_lowercase());
-        } else if part.starts_with('/') && part.ends_with('/') {
-            if let Ok(re) = Regex::new(&part[1..part.len() - 1]) {
-                regex = Some(re);
-            }
-        } else {
-            terms.insert(part.to_lowercase());
+/// Styles the characters of `text` at the fuzzy matcher's `indices` as matched.
+fn highlight_chars(text: &str, indices: &[usize], base: Style) -> Line<'static> {
+    let matched: HashSet<usize> = indices.iter().copied().collect();
+    let highlight = base.fg(D_PINK).add_modifier(Modifier::UNDERLINED);
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+
+    for (i, c) in text.chars().enumerate() {
+        let is_matched = matched.contains(&i);
+        if is_matched != run_matched && !run.is_empty() {
+            let style = if run_matched { highlight } else { base };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
         }
+        run_matched = is_matched;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(Span::styled(run, if run_matched { highlight } else { base }));
     }
+    Line::from(spans)
+}
 
-    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
-    let mut scored_skins: Vec<(i64, &Skin)> = skins
+/// Character indices in `text_lower` that any of the query `terms` fuzzy-matched.
+fn fuzzy_match_indices(
+    text_lower: &str,
+    terms: &HashSet<String>,
+    matcher: &fuzzy_matcher::skim::SkimMatcherV2,
+) -> Vec<usize> {
+    terms
         .iter()
-        .filter_map(|skin| {
-            // Apply explicit filters
-            if let Some(rarity) = filters.get("rarity") {
-                if skin.rarity_lower != *rarity {
-                    return None;
-                }
-            }
-            if let Some(event) = filters.get("event") {
-                if !skin.event_lower.contains(event) {
-                    return None;
-                }
-            }
-            if let Some(year) = filters.get("year") {
-                if skin.year_str != *year {
-                    return None;
-                }
-            }
-
-            // Apply regex if present
-            if let Some(re) = &regex {
-                if !re.is_match(&skin.name_lower) && !re.is_match(&skin.event_lower) {
-                    return None;
-                }
-            }
-
-            // Score based on terms
-            let mut score = 0;
-            let mut matched = false;
-
-            for term in &terms {
-                // Special handling for "favorite"
-                if term.contains("fav") {
-                    if favorites.contains(&skin.name) {
-                        score += 1000;
-                        matched = true;
-                    }
-                }
-
-                // Match years (e.g., "2", "20", "202" matches "2022", "2023", etc.)
-                if !skin.year_str.is_empty() && skin.year_str.contains(term) {
-                    score += 800;
-                    matched = true;
-                }
-
-                // Match tags (e.g., "po", "pop", "popul" matches "popular")
-                for tag in &skin.tags_lower {
-                    if tag.contains(term) {
-                        score += 600;
-                        matched = true;
-                    }
-                }
+        .filter_map(|term| matcher.fuzzy_indices(text_lower, term))
+        .flat_map(|(_, indices)| indices)
+        .collect()
+}
 
-                // Fuzzy match name and event
-                if let Some(s) = matcher.fuzzy_match(&skin.name_lower, term) {
-                    score += s;
-                    matched = true;
-                }
-                if let Some(s) = matcher.fuzzy_match(&skin.event_lower, term) {
-                    score += s;
-                    matched = true;
-                }
-                if skin.rarity_lower.contains(term) {
-                    score += 1000;
-                    matched = true;
-                }
-            }
+/// Renders the comma-separated tags cell, highlighting tags the query matched.
+fn highlight_tags(
+    tags: &[String],
+    terms: &HashSet<String>,
+    matcher: &fuzzy_matcher::skim::SkimMatcherV2,
+) -> Line<'static> {
+    let base = Style::default().fg(D_FOREGROUND);
+    let mut spans = Vec::new();
+    for (i, tag) in tags.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(", ", base));
+        }
+        // Tags match by substring, so only those terms are highlighted here
+        let tag_lower = tag.to_lowercase();
+        let tag_terms: HashSet<String> =
+            terms.iter().filter(|term| tag_lower.contains(term.as_str())).cloned().collect();
+        let indices = fuzzy_match_indices(&tag_lower, &tag_terms, matcher);
+        spans.extend(highlight_chars(tag, &indices, base).spans);
+    }
+    Line::from(spans)
+}
 
-            if matched || (!filters.is_empty() && terms.is_empty()) {
-                Some((score, skin))
-            } else {
-                None
-            }
-        })
-        .collect();
+/// Index of the part holding a facet group's filter: the first `field:value` part for `field`.
+/// Later ones, like a chip's, narrow the results on their own.
+fn facet_filter<S: AsRef<str>>(parts: &[S], field: &str) -> Option<usize> {
+    parts.iter().position(|part| split_filter(part.as_ref()).is_some_and(|(f, _)| f == field))
+}
 
-    scored_skins.sort_by(|a, b| b.0.cmp(&a.0));
-    scored_skins.into_iter().map(|(_, s)| s.clone()).collect()
+/// The sidebar's facets: every rarity, event, year and tag, grouped in that order. Events are
+/// whole names rather than the words `all_terms` has, and match their Exquisite variants too.
+fn load_facets(all_terms: &HashMap<String, TermInfo>) -> Vec<(&'static str, String)> {
+    let terms_where = |is_kind: fn(&TermInfo) -> bool| {
+        let mut terms: Vec<String> = all_terms
+            .iter()
+            .filter(|(_, info)| info.alias_of.is_none() && is_kind(info))
+            .map(|(term, _)| term.clone())
+            .collect();
+        terms.sort();
+        terms
+    };
+    let mut rarities = terms_where(|info| info.is_rarity);
+    rarities.sort_by_key(|term| rarity_tier(term));
+    let mut events: Vec<String> = EVENTS.iter().map(|event| event.to_lowercase()).collect();
+    events.sort();
+    let mut years = terms_where(|info| info.is_year);
+    years.reverse();
+    let tags = terms_where(|info| info.is_tag);
+
+    [("rarity", rarities), ("event", events), ("year", years), ("tag", tags)]
+        .into_iter()
+        .flat_map(|(field, terms)| terms.into_iter().map(move |term| (field, term)))
+        .collect()
 }
@@ -0,0 +1,61 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{config::config_file, Skin};
+
+fn get_rarity_weights_path() -> PathBuf {
+    config_file("rarity_weights.json")
+}
+
+/// Relative odds of a random pick per lowercase rarity, e.g. `{"red": 1, "teal": 5}`.
+/// Rarities left out weigh 1, and a weight of 0 never gets picked.
+pub fn load_rarity_weights() -> HashMap<String, u64> {
+    fs::read_to_string(get_rarity_weights_path())
+        .ok()
+        .and_then(|content| serde_json::from_str::<HashMap<String, u64>>(&content).ok())
+        .map(|weights| weights.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect())
+        .unwrap_or_default()
+}
+
+/// Picks one of `skins` at random, weighted by rarity.
+pub fn random_skin<'a>(skins: &'a [Skin], weights: &HashMap<String, u64>) -> Option<&'a Skin> {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+    let mut total = 0;
+    let weighted: Vec<(u64, &Skin)> = skins
+        .iter()
+        .map(|skin| {
+            total += weights.get(&skin.rarity_lower).copied().unwrap_or(1);
+            (total, skin)
+        })
+        .collect();
+    if total == 0 {
+        return None;
+    }
+    let target = splitmix64(nanos as u64) % total;
+    weighted.into_iter().find(|(cumulative, _)| target < *cumulative).map(|(_, skin)| skin)
+}
+
+/// The skin featured for the current UTC day. It's seeded from the date and picked from the
+/// catalog sorted by name, so every binary and every machine lands on the same one.
+pub fn skin_of_the_day(skins: &[Skin]) -> Option<&Skin> {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let mut sorted: Vec<&Skin> = skins.iter().collect();
+    sorted.sort_by(|a, b| a.name_lower.cmp(&b.name_lower));
+    if sorted.is_empty() {
+        return None;
+    }
+    let index = splitmix64(secs / 86_400) % sorted.len() as u64;
+    Some(sorted[index as usize])
+}
+
+/// A single round of SplitMix64, which is plenty to scatter a seed without pulling in `rand`.
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,243 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use serde::{Deserialize, Serialize};
+
+use crate::{config::config_file, Skin};
+
+/// Weights used to turn a query match into a score. Every pola binary ranks with these, so a
+/// profile behaves the same in the TUI and the CLI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankingProfile {
+    pub name: String,
+    /// Points when a term contains "fav" and the skin is a favorite.
+    pub favorite: i64,
+    pub rarity: i64,
+    pub year: i64,
+    /// Points per tag containing a term.
+    pub tag: i64,
+    /// Percentage applied to the fuzzy matcher's name score.
+    pub name_percent: i64,
+    /// Percentage applied to the fuzzy matcher's event score.
+    pub event_percent: i64,
+    /// Extra points for skins carrying these tags, e.g. `popular`.
+    pub tag_boosts: HashMap<String, i64>,
+    /// Points for a skin released this year, halving for each year before.
+    pub recency_boost: i64,
+    /// Extra points for any favorited skin that matches.
+    pub favorite_boost: i64,
+}
+
+impl Default for RankingProfile {
+    fn default() -> Self {
+        RankingProfile {
+            name: "balanced".to_string(),
+            favorite: 1000,
+            rarity: 1000,
+            year: 800,
+            tag: 600,
+            name_percent: 100,
+            event_percent: 100,
+            tag_boosts: HashMap::from([("popular".to_string(), 300)]),
+            recency_boost: 0,
+            favorite_boost: 0,
+        }
+    }
+}
+
+/// Per-field contributions to a skin's score, shown in explain mode.
+#[derive(Debug, Clone, Default)]
+pub struct ScoreBreakdown {
+    pub favorite: i64,
+    pub rarity: i64,
+    pub year: i64,
+    pub tag: i64,
+    pub name: i64,
+    pub event: i64,
+    pub tag_boost: i64,
+    pub recency: i64,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> i64 {
+        self.favorite
+            + self.rarity
+            + self.year
+            + self.tag
+            + self.name
+            + self.event
+            + self.tag_boost
+            + self.recency
+    }
+
+    /// Non-zero contributions as `(field, points)`, in display order.
+    pub fn fields(&self) -> Vec<(&'static str, i64)> {
+        [
+            ("Favorite", self.favorite),
+            ("Rarity", self.rarity),
+            ("Year", self.year),
+            ("Tags", self.tag),
+            ("Name", self.name),
+            ("Event", self.event),
+            ("Tag boost", self.tag_boost),
+            ("Recency", self.recency),
+        ]
+        .into_iter()
+        .filter(|(_, points)| *points != 0)
+        .collect()
+    }
+}
+
+impl RankingProfile {
+    /// Scores `skin` against the free-text `terms`, returning `None` if no term matched.
+    pub fn score<'t>(
+        &self,
+        skin: &Skin,
+        terms: impl IntoIterator<Item = &'t str>,
+        is_favorite: bool,
+        matcher: &SkimMatcherV2,
+    ) -> Option<ScoreBreakdown> {
+        let mut breakdown = ScoreBreakdown::default();
+        let mut matched = false;
+
+        for term in terms {
+            // Special handling for "favorite"
+            if term.contains("fav") && is_favorite {
+                breakdown.favorite += self.favorite;
+                matched = true;
+            }
+
+            // Match years (e.g., "2", "20", "202" matches "2022", "2023", etc.)
+            if !skin.year_str.is_empty() && skin.year_str.contains(term) {
+                breakdown.year += self.year;
+                matched = true;
+            }
+
+            // Match tags (e.g., "po", "pop", "popul" matches "popular")
+            for tag in &skin.tags_lower {
+                if tag.contains(term) {
+                    breakdown.tag += self.tag;
+                    matched = true;
+                }
+            }
+
+            // Fuzzy match name and event
+            if let Some(s) = matcher.fuzzy_match(&skin.name_lower, term) {
+                breakdown.name += s * self.name_percent / 100;
+                matched = true;
+            }
+            if let Some(s) = matcher.fuzzy_match(&skin.event_lower, term) {
+                breakdown.event += s * self.event_percent / 100;
+                matched = true;
+            }
+            if skin.rarity_lower.contains(term) {
+                breakdown.rarity += self.rarity;
+                matched = true;
+            }
+        }
+
+        if matched {
+            self.apply_boosts(skin, is_favorite, &mut breakdown);
+            Some(breakdown)
+        } else {
+            None
+        }
+    }
+
+    /// Adds the profile's tag, recency and favorite boosts, which apply regardless of the terms.
+    pub fn apply_boosts(&self, skin: &Skin, is_favorite: bool, breakdown: &mut ScoreBreakdown) {
+        breakdown.tag_boost =
+            skin.tags_lower.iter().filter_map(|tag| self.tag_boosts.get(tag)).sum();
+        // Only read the clock when it matters, since wasm builds have no system time
+        if let Some(year) = skin.year.filter(|_| self.recency_boost != 0) {
+            let age = current_year().saturating_sub(year).min(62);
+            breakdown.recency = self.recency_boost >> age;
+        }
+        if is_favorite {
+            breakdown.favorite += self.favorite_boost;
+        }
+    }
+}
+
+/// The ranking profiles a user can pick from, stored next to the key bindings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankingConfig {
+    pub active: String,
+    pub profiles: Vec<RankingProfile>,
+}
+
+impl Default for RankingConfig {
+    fn default() -> Self {
+        let balanced = RankingProfile::default();
+        let classic = RankingProfile {
+            name: "classic".to_string(),
+            tag_boosts: HashMap::new(),
+            ..RankingProfile::default()
+        };
+        let newest = RankingProfile {
+            name: "newest".to_string(),
+            recency_boost: 2000,
+            ..RankingProfile::default()
+        };
+        let favorites = RankingProfile {
+            name: "favorites".to_string(),
+            favorite_boost: 3000,
+            ..RankingProfile::default()
+        };
+        RankingConfig {
+            active: balanced.name.clone(),
+            profiles: vec![balanced, classic, newest, favorites],
+        }
+    }
+}
+
+impl RankingConfig {
+    /// The active profile, falling back to the first one if `active` names no profile.
+    pub fn active_profile(&self) -> RankingProfile {
+        self.profiles
+            .iter()
+            .find(|p| p.name == self.active)
+            .or_else(|| self.profiles.first())
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+pub fn get_ranking_config_path() -> PathBuf {
+    config_file("ranking_profiles.json")
+}
+
+/// Loads the ranking profiles, writing the built-in ones on first run so they can be edited.
+pub fn load_ranking_config() -> RankingConfig {
+    let path = get_ranking_config_path();
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => {
+            let config = RankingConfig::default();
+            let _ = save_ranking_config(&config);
+            config
+        },
+    }
+}
+
+pub fn save_ranking_config(config: &RankingConfig) -> std::io::Result<()> {
+    let path = get_ranking_config_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(config)?)
+}
+
+/// The current UTC year, close enough for recency boosts and plausibility checks.
+pub(crate) fn current_year() -> u32 {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    // Average Gregorian year length in seconds
+    1970 + (secs / 31_556_952) as u32
+}
@@ -0,0 +1,451 @@
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, buffer::Buffer, prelude::*, Terminal};
+use serde_json::json;
+
+use crate::{
+    config::config_file, default_key_bindings, get_key_binding_str, parse_key_chord, ui, AppState,
+    EventSource, InputMode, UserConfig,
+};
+
+const DEFAULT_WIDTH: u16 = 120;
+const DEFAULT_HEIGHT: u16 = 30;
+
+/// What `assert` lines can check, and `--state` prints.
+const FIELDS: &[&str] =
+    &["input", "results.len", "selected", "page", "favorites.len", "suggestion", "mode", "status"];
+
+/// One line of a script.
+#[derive(Clone)]
+pub enum Step {
+    /// `type "void"` or `key Ctrl+f`, played through `handle_event` like real input.
+    Keys(Vec<Event>),
+    /// `action toggle_favorite`, run as if its key had been pressed.
+    Action(String),
+    /// `assert results.len == 3`
+    Assert { field: String, op: String, expected: String },
+}
+
+#[derive(Debug)]
+pub struct ScriptError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// `pola script <file|-> [--state] [--defaults] [--size WxH]`: plays a script against the TUI
+/// on an off-screen terminal and prints the last frame, or the app's state as JSON with
+/// `--state`. `--defaults` ignores the user's configuration and keeps favorites in a scratch
+/// directory, so a script replays the same for anyone. Returns whether every line succeeded.
+pub fn run(args: &[String]) -> io::Result<bool> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidInput, message.to_string());
+    let mut path = None;
+    let mut state = false;
+    let mut defaults = false;
+    let (mut width, mut height) = (DEFAULT_WIDTH, DEFAULT_HEIGHT);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--state" => state = true,
+            "--defaults" => defaults = true,
+            "--size" => {
+                (width, height) = args
+                    .next()
+                    .and_then(|size| size.split_once('x'))
+                    .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                    .ok_or_else(|| invalid("--size needs a size like 120x30"))?;
+            },
+            _ if path.is_none() => path = Some(arg.clone()),
+            _ => return Err(invalid(&format!("unexpected argument {:?}", arg))),
+        }
+    }
+    let path = path.ok_or_else(|| invalid("usage: pola script <file|-> [--state] [--defaults]"))?;
+    let text = if path == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        fs::read_to_string(&path)?
+    };
+
+    let steps = match parse_script(&text) {
+        Ok(steps) => steps,
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            return Ok(false);
+        },
+    };
+
+    let scratch = std::env::temp_dir().join(format!("pola-script-{}", std::process::id()));
+    let mut app = if defaults {
+        fs::create_dir_all(&scratch)?;
+        let mut app = AppState::with_config(UserConfig::defaults(&scratch));
+        // Today's pick would change the search bar every day
+        app.daily_skin = None;
+        app
+    } else {
+        AppState::new()
+    };
+    app.headless = true;
+    app.update_search();
+
+    let mut terminal = Terminal::new(TestBackend::new(width, height))?;
+    let result = app.run_script(&steps, &mut terminal);
+    terminal.draw(|f| ui(f, &mut app))?;
+    if state {
+        println!("{}", serde_json::to_string_pretty(&app.script_state()).unwrap_or_default());
+    } else {
+        print!("{}", screen_text(terminal.backend().buffer()));
+    }
+    if defaults {
+        fs::remove_dir_all(&scratch)?;
+    }
+
+    match result {
+        Ok(()) => Ok(true),
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            Ok(false)
+        },
+    }
+}
+
+/// Parses a script, one step per line. Blank lines and lines starting with `#` are skipped.
+pub fn parse_script(text: &str) -> Result<Vec<(usize, Step)>, ScriptError> {
+    let actions: Vec<String> = default_key_bindings()
+        .into_keys()
+        .map(|action| action.split_once(':').map_or(action.clone(), |(_, name)| name.to_string()))
+        .collect();
+
+    let mut steps = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: String| ScriptError { line: i + 1, message };
+        let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let rest = rest.trim();
+        let step = match command {
+            "type" => {
+                let text = unquote(rest).ok_or_else(|| error(format!("bad string {}", rest)))?;
+                Step::Keys(
+                    text.chars()
+                        .map(|c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)))
+                        .collect(),
+                )
+            },
+            "key" => {
+                let chord = parse_key_chord(rest)
+                    .ok_or_else(|| error(format!("unknown key {:?}", rest)))?;
+                Step::Keys(
+                    chord
+                        .into_iter()
+                        .map(|(code, modifiers)| Event::Key(KeyEvent::new(code, modifiers)))
+                        .collect(),
+                )
+            },
+            "action" => {
+                // Macros can't run from scripts, see `run_macro`
+                if !actions.iter().any(|action| action == rest) {
+                    return Err(error(format!("unknown action {:?}", rest)));
+                }
+                Step::Action(rest.to_string())
+            },
+            "assert" => {
+                let mut parts = rest.splitn(3, ' ').map(str::trim);
+                let (Some(field), Some(op), Some(expected)) =
+                    (parts.next(), parts.next(), parts.next())
+                else {
+                    return Err(error("expected `assert <field> <op> <value>`".to_string()));
+                };
+                if !FIELDS.contains(&field) {
+                    return Err(error(format!(
+                        "unknown field {:?}, try {}",
+                        field,
+                        FIELDS.join(", ")
+                    )));
+                }
+                if !["==", "!=", "<", "<=", ">", ">="].contains(&op) {
+                    return Err(error(format!("unknown comparison {:?}", op)));
+                }
+                let expected =
+                    unquote(expected).ok_or_else(|| error(format!("bad string {}", expected)))?;
+                Step::Assert { field: field.to_string(), op: op.to_string(), expected }
+            },
+            _ => return Err(error(format!("unknown command {:?}", command))),
+        };
+        steps.push((i + 1, step));
+    }
+    Ok(steps)
+}
+
+/// A JSON string literal, or the text as it is.
+fn unquote(text: &str) -> Option<String> {
+    if text.starts_with('"') {
+        serde_json::from_str(text).ok()
+    } else {
+        Some(text.to_string())
+    }
+}
+
+/// The rendered frame as text, one row per line without trailing spaces.
+pub fn screen_text(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    for y in 0..buffer.area.height {
+        let line: String =
+            (0..buffer.area.width).map(|x| buffer.get(x, y).symbol.as_str()).collect();
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+fn compare(actual: &str, op: &str, expected: &str) -> bool {
+    let ordering = match (actual.parse::<i64>(), expected.parse::<i64>()) {
+        (Ok(actual), Ok(expected)) => actual.cmp(&expected),
+        _ => actual.cmp(expected),
+    };
+    match op {
+        "==" => ordering.is_eq(),
+        "!=" => ordering.is_ne(),
+        "<" => ordering.is_lt(),
+        "<=" => ordering.is_le(),
+        ">" => ordering.is_gt(),
+        _ => ordering.is_ge(),
+    }
+}
+
+/// Writes recorded steps back out as a script. Runs of typed characters become one `type`
+/// line, other keys a `key` line each.
+pub fn script_text(steps: &[Step]) -> String {
+    let mut lines = Vec::new();
+    let mut typed = String::new();
+    let flush = |typed: &mut String, lines: &mut Vec<String>| {
+        if !typed.is_empty() {
+            lines.push(format!("type {}", json!(typed)));
+            typed.clear();
+        }
+    };
+    for step in steps {
+        match step {
+            Step::Keys(events) => {
+                for event in events {
+                    let Event::Key(key) = event else {
+                        continue;
+                    };
+                    match key.code {
+                        KeyCode::Char(c) if (key.modifiers - KeyModifiers::SHIFT).is_empty() => {
+                            typed.push(c)
+                        },
+                        _ => {
+                            flush(&mut typed, &mut lines);
+                            lines.push(format!(
+                                "key {}",
+                                get_key_binding_str(&(key.code, key.modifiers))
+                            ));
+                        },
+                    }
+                }
+            },
+            Step::Action(action) => {
+                flush(&mut typed, &mut lines);
+                lines.push(format!("action {}", action));
+            },
+            Step::Assert { field, op, expected } => {
+                flush(&mut typed, &mut lines);
+                lines.push(format!("assert {} {} {}", field, op, json!(expected)));
+            },
+        }
+    }
+    flush(&mut typed, &mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+pub fn default_macros_dir() -> PathBuf {
+    config_file("macros")
+}
+
+impl AppState {
+    /// Plays `steps` in order. Keys get a frame drawn before each, like the main loop, and a
+    /// dialog they open reads its keys from the `key` and `type` lines right after.
+    pub fn run_script<B: Backend>(
+        &mut self,
+        steps: &[(usize, Step)],
+        terminal: &mut Terminal<B>,
+    ) -> Result<(), ScriptError> {
+        let previous =
+            std::mem::replace(&mut self.events, EventSource::Scripted(Default::default()));
+        let result = self.play_steps(steps, terminal);
+        self.events = previous;
+        result
+    }
+
+    fn play_steps<B: Backend>(
+        &mut self,
+        steps: &[(usize, Step)],
+        terminal: &mut Terminal<B>,
+    ) -> Result<(), ScriptError> {
+        let mut i = 0;
+        while i < steps.len() {
+            let line = steps[i].0;
+            let failed = |e: io::Error| {
+                let message = match e.kind() {
+                    io::ErrorKind::UnexpectedEof => {
+                        "a dialog is still waiting for keys".to_string()
+                    },
+                    _ => e.to_string(),
+                };
+                ScriptError { line, message }
+            };
+            match &steps[i].1 {
+                Step::Keys(_) => {
+                    // Consecutive key lines share a queue, so dialogs can read past their line
+                    let mut events = Vec::new();
+                    while let Some((_, Step::Keys(keys))) = steps.get(i) {
+                        events.extend(keys.iter().cloned());
+                        i += 1;
+                    }
+                    self.events = EventSource::Scripted(events.into());
+                    loop {
+                        terminal.draw(|f| ui(f, self)).map_err(failed)?;
+                        match &self.events {
+                            EventSource::Scripted(events) if !events.is_empty() => {},
+                            _ => break,
+                        }
+                        if let Some(event) = self.poll_event().map_err(failed)? {
+                            self.handle_event(event, terminal).map_err(failed)?;
+                        }
+                    }
+                    continue;
+                },
+                Step::Action(action) => {
+                    if let Some(recording) = &mut self.recording {
+                        recording.push(Step::Action(action.clone()));
+                    }
+                    terminal.draw(|f| ui(f, self)).map_err(failed)?;
+                    self.run_action(action, terminal).map_err(failed)?;
+                },
+                Step::Assert { field, op, expected } => {
+                    let actual = self.script_field(field);
+                    if !compare(&actual, op, expected) {
+                        return Err(ScriptError {
+                            line,
+                            message: format!(
+                                "expected {} {} {:?}, but it was {:?}",
+                                field, op, expected, actual
+                            ),
+                        });
+                    }
+                },
+            }
+            i += 1;
+        }
+        Ok(())
+    }
+
+    /// The value of one of `FIELDS`.
+    fn script_field(&self, field: &str) -> String {
+        match field {
+            "input" => self.input.clone(),
+            "results.len" => self.results.len().to_string(),
+            "selected" => self.selected_skin().map(|skin| skin.name.clone()).unwrap_or_default(),
+            "page" => (self.current_page + 1).to_string(),
+            "favorites.len" => self.favorites.len().to_string(),
+            "suggestion" => self.suggestion.clone().unwrap_or_default(),
+            "mode" => match self.mode {
+                InputMode::Insert => "insert".to_string(),
+                InputMode::Normal => "normal".to_string(),
+            },
+            "status" => self.status_message.clone().unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    /// Every field plus the results and favorites themselves, for `--state`.
+    fn script_state(&self) -> serde_json::Value {
+        let mut favorites: Vec<&String> = self.favorites.iter().collect();
+        favorites.sort();
+        let mut state = json!({
+            "results": self.results.iter().map(|skin| &skin.name).collect::<Vec<_>>(),
+            "favorites": favorites,
+        });
+        for &field in FIELDS {
+            let value = self.script_field(field);
+            state[field] = match field {
+                "results.len" | "page" | "favorites.len" => json!(value.parse::<usize>().ok()),
+                _ => json!(value),
+            };
+        }
+        state
+    }
+
+    /// Plays `~/.skin_tui/macros/<name>.txt`, bound to a key as `"macro:<name>"`. Macros can't
+    /// start other macros, so one that binds its own key doesn't loop forever.
+    pub fn run_macro<B: Backend>(&mut self, name: &str, terminal: &mut Terminal<B>) {
+        if matches!(self.events, EventSource::Scripted(_)) {
+            return;
+        }
+        let path = self.macros_dir.join(format!("{}.txt", name));
+        let steps = fs::read_to_string(&path)
+            .map_err(|e| format!("Couldn't read {}: {}", path.display(), e))
+            .and_then(|text| parse_script(&text).map_err(|e| e.to_string()));
+        // A recording gets the macro's steps once, not each key it plays
+        let recording = self.recording.take();
+        let result = steps.and_then(|steps| {
+            self.run_script(&steps, terminal).map_err(|e| e.to_string())?;
+            Ok(steps)
+        });
+        self.recording = recording;
+        match result {
+            Ok(steps) => {
+                if let Some(recording) = &mut self.recording {
+                    recording.extend(
+                        steps
+                            .into_iter()
+                            .map(|(_, step)| step)
+                            .filter(|step| !matches!(step, Step::Assert { .. })),
+                    );
+                }
+            },
+            Err(e) => self.status_message = Some(format!("Macro {} failed, {}", name, e)),
+        }
+    }
+
+    /// Starts recording, or saves what was recorded as the `recorded` macro.
+    pub fn toggle_recording(&mut self) {
+        let Some(steps) = self.recording.take() else {
+            self.recording = Some(Vec::new());
+            return;
+        };
+        let path = self.macros_dir.join("recorded.txt");
+        let result = fs::create_dir_all(&self.macros_dir)
+            .and_then(|()| fs::write(&path, script_text(&steps)));
+        self.status_message = Some(match result {
+            Ok(()) => format!("Saved macro to {}, bind it as \"macro:recorded\"", path.display()),
+            Err(e) => format!("Couldn't save macro to {}: {}", path.display(), e),
+        });
+    }
+
+    /// Drops the last `count` recorded keys, the chord that ran an action.
+    pub fn forget_recorded_keys(&mut self, count: usize) {
+        if let Some(recording) = &mut self.recording {
+            for _ in 0..count {
+                match recording.last() {
+                    Some(Step::Keys(_)) => recording.pop(),
+                    _ => break,
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use std::collections::{HashMap, HashSet};
+
+use fuzzy_matcher::FuzzyMatcher;
+use regex::Regex;
+
+use crate::{
+    ranking::{RankingProfile, ScoreBreakdown},
+    Skin,
+};
+
+#[derive(Default, Clone)]
+pub struct TermInfo {
+    pub is_name: bool,
+    pub is_event: bool,
+    pub is_rarity: bool,
+    pub is_tag: bool,
+    pub is_year: bool,
+    pub alias_of: Option<String>,
+}
+
+pub fn load_all_terms(
+    skins: &[Skin],
+    aliases: &HashMap<String, String>,
+) -> HashMap<String, TermInfo> {
+    let mut terms: HashMap<String, TermInfo> = HashMap::new();
+    for skin in skins {
+        for word in skin.name_lower.split_whitespace() {
+            terms.entry(word.to_string()).or_default().is_name = true;
+        }
+        for word in skin.event_lower.split_whitespace() {
+            terms.entry(word.to_string()).or_default().is_event = true;
+        }
+        terms.entry(skin.rarity_lower.clone()).or_default().is_rarity = true;
+        for tag in &skin.tags_lower {
+            terms.entry(tag.clone()).or_default().is_tag = true;
+        }
+        if !skin.year_str.is_empty() {
+            terms.entry(skin.year_str.clone()).or_default().is_year = true;
+        }
+    }
+
+    // Aliases take on the kinds of the words they expand to, so they are styled alike
+    for (alias, canonical) in aliases {
+        let mut info = TermInfo { alias_of: Some(canonical.clone()), ..TermInfo::default() };
+        for word in canonical.split_whitespace() {
+            if let Some(target) = terms.get(word) {
+                info.is_name |= target.is_name;
+                info.is_event |= target.is_event;
+                info.is_rarity |= target.is_rarity;
+                info.is_tag |= target.is_tag;
+                info.is_year |= target.is_year;
+            }
+        }
+        terms.entry(alias.clone()).or_insert(info);
+    }
+    terms
+}
+
+pub struct ParsedQuery {
+    /// `field:value` filters, every one of which a skin has to pass. Values listed with commas,
+    /// `rarity:pink,red`, are alternatives within one filter.
+    pub filters: Vec<(String, Vec<String>)>,
+    /// `-field:value` filters, which drop every skin they match.
+    pub exclusions: Vec<(String, String)>,
+    pub terms: HashSet<String>,
+    pub regex: Option<Regex>,
+}
+
+pub fn parse_query(query: &str) -> ParsedQuery {
+    let mut filters: Vec<(String, Vec<String>)> = Vec::new();
+    let mut exclusions: Vec<(String, String)> = Vec::new();
+    let mut terms: HashSet<String> = HashSet::new();
+    let mut regex: Option<Regex> = None;
+
+    for part in split_query(query) {
+        if let Some((field, values)) = split_filter(part) {
+            if values.is_empty() {
+                continue;
+            }
+            match field.strip_prefix('-') {
+                Some(field) => {
+                    exclusions.extend(values.into_iter().map(|value| (field.to_string(), value)))
+                },
+                None => filters.push((field, values)),
+            }
+        } else if part.starts_with('/') && part.ends_with('/') {
+            if let Ok(re) = Regex::new(&part[1..part.len() - 1]) {
+                regex = Some(re);
+            }
+        } else {
+            terms.insert(part.to_lowercase());
+        }
+    }
+
+    ParsedQuery { filters, exclusions, terms, regex }
+}
+
+/// Splits `query` on whitespace outside double quotes, so `event:"valentine case" red` is two
+/// parts. The quotes stay in the parts.
+pub fn split_query(query: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = None;
+    let mut quoted = false;
+    for (i, c) in query.char_indices() {
+        if c.is_whitespace() && !quoted {
+            if let Some(start) = start.take() {
+                parts.push(&query[start..i]);
+            }
+        } else {
+            start.get_or_insert(i);
+            if c == '"' {
+                quoted = !quoted;
+            }
+        }
+    }
+    if let Some(start) = start {
+        parts.push(&query[start..]);
+    }
+    parts
+}
+
+/// The lowercase field and values of a `field:value` part. The field keeps its `-` when the part
+/// is an exclusion, and the values are its comma-separated alternatives without their quotes.
+pub fn split_filter(part: &str) -> Option<(String, Vec<String>)> {
+    let (field, value) = part.split_once(':')?;
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in value.chars().chain([',']) {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                let value = current.trim().to_lowercase();
+                if !value.is_empty() {
+                    values.push(value);
+                }
+                current.clear();
+            },
+            c => current.push(c),
+        }
+    }
+    Some((field.to_lowercase(), values))
+}
+
+/// A `field:value` part for `values`, quoting the ones a query couldn't otherwise hold.
+pub fn filter_term(field: &str, values: &[&str]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|value| {
+            if value.contains(|c: char| c.is_whitespace() || c == ',' || c == '"') {
+                format!("\"{}\"", value.replace('"', ""))
+            } else {
+                value.to_string()
+            }
+        })
+        .collect();
+    format!("{}:{}", field, values.join(","))
+}
+
+/// Whether `skin` passes a single `field:value` filter, or `None` for fields that don't exist.
+pub fn matches_filter(
+    skin: &Skin,
+    field: &str,
+    value: &str,
+    favorites: &HashSet<String>,
+) -> Option<bool> {
+    match field {
+        "rarity" => Some(skin.rarity_lower == value),
+        "event" => Some(skin.event_lower.contains(value)),
+        "year" => Some(skin.year_str == value),
+        "tag" => Some(
+            skin.tags_lower.contains(value)
+                || (value == "favorite" && favorites.contains(&skin.name)),
+        ),
+        _ => None,
+    }
+}
+
+/// Recomputes the score of a single result for explain mode.
+pub fn explain_skin(
+    skin: &Skin,
+    query: &str,
+    favorites: &HashSet<String>,
+    profile: &RankingProfile,
+) -> ScoreBreakdown {
+    let parsed = parse_query(query);
+    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+    let is_favorite = favorites.contains(&skin.name);
+    profile
+        .score(skin, parsed.terms.iter().map(String::as_str), is_favorite, &matcher)
+        .unwrap_or_else(|| {
+            let mut breakdown = ScoreBreakdown::default();
+            profile.apply_boosts(skin, is_favorite, &mut breakdown);
+            breakdown
+        })
+}
+
+pub fn search_skins(
+    skins: &[Skin],
+    name_map: &HashMap<String, usize>,
+    query: &str,
+    favorites: &HashSet<String>,
+    profile: &RankingProfile,
+) -> Vec<Skin> {
+    let ParsedQuery { filters, exclusions, terms, regex } = parse_query(query);
+
+    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+    let mut scored_skins: Vec<(i64, &Skin)> = skins
+        .iter()
+        .filter_map(|skin| {
+            // Apply explicit filters; unknown fields are ignored
+            let is_favorite = favorites.contains(&skin.name);
+            let passes_filters = filters.iter().all(|(field, values)| {
+                values.iter().any(|v| matches_filter(skin, field, v, favorites).unwrap_or(true))
+            });
+            let excluded = exclusions
+                .iter()
+                .any(|(field, v)| matches_filter(skin, field, v, favorites).unwrap_or(false));
+            if !passes_filters || excluded {
+                return None;
+            }
+
+            // Apply regex if present
+            if let Some(re) = &regex {
+                if !re.is_match(&skin.name_lower) && !re.is_match(&skin.event_lower) {
+                    return None;
+                }
+            }
+
+            match profile.score(skin, terms.iter().map(String::as_str), is_favorite, &matcher) {
+                Some(breakdown) => Some((breakdown.total(), skin)),
+                None if (!filters.is_empty() || !exclusions.is_empty()) && terms.is_empty() => {
+                    // Filter-only queries still rank by the profile's boosts
+                    let mut breakdown = ScoreBreakdown::default();
+                    profile.apply_boosts(skin, is_favorite, &mut breakdown);
+                    Some((breakdown.total(), skin))
+                },
+                None => None,
+            }
+        })
+        .collect();
+
+    scored_skins.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+    let mut results: Vec<Skin> = scored_skins.into_iter().map(|(_, s)| s.clone()).collect();
+    exact_name_first(&mut results, name_map, query);
+    results
+}
+
+/// Moves the skin named by the whole of `query` to the front, if it's among `results`, ahead of
+/// any that scored higher. It never brings back a skin the rest of the query dropped.
+pub fn exact_name_first(results: &mut [Skin], name_map: &HashMap<String, usize>, query: &str) {
+    let name = query.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    if !name_map.contains_key(&name) {
+        return;
+    }
+    if let Some(i) = results.iter().position(|skin| skin.name_lower == name) {
+        results[..=i].rotate_right(1);
+    }
+}
+
+/// Completions for the last word of `input`, best first, along with what kind of term every
+/// candidate is. Names, events and rarities come from `results`, so they narrow with the query.
+pub fn suggest_terms(
+    input: &str,
+    skins: &[Skin],
+    results: &[Skin],
+    favorites: &HashSet<String>,
+    aliases: &HashMap<String, String>,
+    all_terms: &HashMap<String, TermInfo>,
+) -> (Vec<String>, HashMap<String, TermInfo>) {
+    let input_parts: Vec<&str> = input.split_whitespace().collect();
+    let last_part_lower = input_parts.last().cloned().unwrap_or("").to_lowercase();
+    let mut current_terms = HashMap::new();
+    if last_part_lower.is_empty() {
+        return (Vec::new(), current_terms);
+    }
+
+    if "favorite".contains(&last_part_lower) && !favorites.is_empty() {
+        current_terms
+            .entry("favorite".to_string())
+            .or_insert_with(|| TermInfo { is_tag: true, ..TermInfo::default() });
+    }
+
+    for skin in skins {
+        if !skin.year_str.is_empty() && skin.year_str.contains(&last_part_lower) {
+            current_terms
+                .entry(skin.year_str.clone())
+                .or_insert_with(|| TermInfo { is_year: true, ..TermInfo::default() });
+        }
+        for tag in &skin.tags_lower {
+            if tag.contains(&last_part_lower) {
+                current_terms
+                    .entry(tag.clone())
+                    .or_insert_with(|| TermInfo { is_tag: true, ..TermInfo::default() });
+            }
+        }
+    }
+
+    for skin in results {
+        if skin.rarity_lower.contains(&last_part_lower) {
+            current_terms
+                .entry(skin.rarity_lower.clone())
+                .or_insert_with(|| TermInfo { is_rarity: true, ..TermInfo::default() });
+        }
+        for word in skin.name_lower.split_whitespace() {
+            if word.contains(&last_part_lower) {
+                current_terms
+                    .entry(word.to_string())
+                    .or_insert_with(|| TermInfo { is_name: true, ..TermInfo::default() });
+            }
+        }
+        if skin.event_lower.contains(&last_part_lower) {
+            current_terms
+                .entry(skin.event_lower.clone())
+                .or_insert_with(|| TermInfo { is_event: true, ..TermInfo::default() });
+        }
+    }
+
+    for alias in aliases.keys() {
+        if alias.contains(&last_part_lower) {
+            if let Some(info) = all_terms.get(alias) {
+                current_terms.entry(alias.clone()).or_insert_with(|| info.clone());
+            }
+        }
+    }
+
+    // Words already typed, counting the words of `field:value` filters' values
+    let typed = split_query(input);
+    let mut used_words: HashSet<String> = HashSet::new();
+    for part in &typed[..typed.len().saturating_sub(1)] {
+        match split_filter(part) {
+            Some((_, values)) => used_words
+                .extend(values.iter().flat_map(|value| value.split_whitespace()).map(String::from)),
+            None => {
+                used_words.insert(part.to_lowercase());
+            },
+        }
+    }
+
+    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+    let mut suggestions = Vec::new();
+    for (term, term_info) in current_terms.iter() {
+        // Multi-word events count as used once all of their words are
+        if term.split_whitespace().all(|word| used_words.contains(word)) {
+            continue;
+        }
+
+        let score = matcher.fuzzy_match(term, &last_part_lower).unwrap_or(i64::MIN);
+        let mut boost = match true {
+            _ if *term == last_part_lower => 10000,
+            _ if *term == "favorite" => 6000,
+            _ if term_info.is_rarity => 5000,
+            _ if term_info.is_name => 4000,
+            _ if term_info.is_event => 3000,
+            _ if term_info.is_year => 2500,
+            _ if term_info.is_tag => 2000,
+            _ => 0,
+        };
+
+        if term.starts_with(&last_part_lower) {
+            boost += 1500;
+        }
+
+        let length_penalty = (term.len() as i64).saturating_sub(4) * 100;
+        let total_score = score + boost - length_penalty;
+
+        if total_score > 0 {
+            suggestions.push((total_score, term.clone()));
+        }
+    }
+
+    suggestions.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    let mut seen = HashSet::new();
+    let suggestions = suggestions
+        .into_iter()
+        .filter(|(_, term)| seen.insert(term.clone()))
+        .take(5)
+        .map(|(_, term)| term)
+        .collect();
+    (suggestions, current_terms)
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use serde_json::{json, Value};
+
+use crate::{
+    aliases::{expand_aliases, load_aliases},
+    load_skins,
+    ranking::{load_ranking_config, RankingProfile},
+    search::{load_all_terms, search_skins, suggest_terms},
+    stats::SkinStats,
+    store::{apply_custom_tags, Store},
+    Skin,
+};
+
+const DEFAULT_PORT: u16 = 7878;
+/// How long a client gets to send its request or read the response. Connections are handled
+/// one at a time, so this bounds how long one that stalls holds up the rest.
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+/// `pola serve [--port N]`: answers JSON requests about the catalog on localhost until killed.
+pub fn run(args: &[String]) -> io::Result<()> {
+    let port = match args.iter().position(|arg| arg == "--port") {
+        Some(i) => args.get(i + 1).and_then(|port| port.parse().ok()).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "--port needs a port number")
+        })?,
+        None => DEFAULT_PORT,
+    };
+    let profile = load_ranking_config().active_profile();
+    let server = Server::bind(("127.0.0.1", port), Store::default(), profile, load_aliases())?;
+    println!("Serving the skin catalog on http://{}", server.local_addr()?);
+    server.run()
+}
+
+/// A small HTTP/1.1 server over the catalog, searching with a ranking profile and aliases like
+/// the TUI does; `pola serve` passes the user's own. Favorites and custom tags are read from `store` on every
+/// request, so edits made in the TUI meanwhile are picked up.
+///
+/// - `GET /skins`, `GET /skins/{name}`
+/// - `GET /search?q=`, `GET /suggest?q=`, `GET /stats`
+/// - `GET /favorites`, `PUT /favorites/{name}`, `DELETE /favorites/{name}`
+/// - `GET /skins/{name}/tags`, `PUT /skins/{name}/tags/{tag}`, `DELETE /skins/{name}/tags/{tag}`
+pub struct Server {
+    listener: TcpListener,
+    store: Store,
+    profile: RankingProfile,
+    aliases: HashMap<String, String>,
+}
+
+impl Server {
+    pub fn bind(
+        addr: impl ToSocketAddrs,
+        store: Store,
+        profile: RankingProfile,
+        aliases: HashMap<String, String>,
+    ) -> io::Result<Server> {
+        Ok(Server { listener: TcpListener::bind(addr)?, store, profile, aliases })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Handles one connection at a time, forever.
+    pub fn run(self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            if let Err(e) = stream.and_then(|stream| self.handle_connection(stream)) {
+                eprintln!("Request failed: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_connection(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        let mut reader = BufReader::new(&stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        // Skip the headers, but drain any body so the client doesn't see a reset
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+        io::copy(&mut reader.take(content_length), &mut io::sink())?;
+
+        let mut parts = request_line.split_whitespace();
+        let (status, body) = match (parts.next(), parts.next()) {
+            (Some(method), Some(target)) => self.respond(method, target),
+            _ => error(400, "malformed request line"),
+        };
+        let body = body.to_string();
+        write!(
+            &stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            status,
+            reason(status),
+            body.len(),
+            body
+        )
+    }
+
+    fn respond(&self, method: &str, target: &str) -> (u16, Value) {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let segments: Vec<String> =
+            path.split('/').filter(|s| !s.is_empty()).map(|s| decode(s, false)).collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+        let q = query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == "q")
+            .map(|(_, value)| decode(value, true))
+            .unwrap_or_default();
+
+        let mut skins = load_skins();
+        apply_custom_tags(&mut skins, &self.store.load_tags());
+        let mut favorites = self.store.load_favorites();
+
+        match (method, segments.as_slice()) {
+            ("GET", ["skins"]) => (200, skins_json(&skins, &favorites)),
+            ("GET", ["skins", name]) => match find_skin(&skins, name) {
+                Some(skin) => (200, skin_json(skin, &favorites)),
+                None => error(404, &format!("no skin named {:?}", name)),
+            },
+            ("GET", ["search"]) => {
+                (200, skins_json(&self.search(&skins, &q, &favorites), &favorites))
+            },
+            ("GET", ["suggest"]) => {
+                let results = self.search(&skins, &q, &favorites);
+                let all_terms = load_all_terms(&skins, &self.aliases);
+                let (suggestions, _) =
+                    suggest_terms(&q, &skins, &results, &favorites, &self.aliases, &all_terms);
+                (200, json!(suggestions))
+            },
+            ("GET", ["stats"]) => (200, stats_json(&SkinStats::compute(&skins, &favorites))),
+            ("GET", ["favorites"]) => {
+                let mut names: Vec<&String> = favorites.iter().collect();
+                names.sort();
+                (200, json!(names))
+            },
+            ("PUT" | "DELETE", ["favorites", name]) => {
+                let Some(skin) = find_skin(&skins, name) else {
+                    return error(404, &format!("no skin named {:?}", name));
+                };
+                if method == "PUT" {
+                    favorites.insert(skin.name.clone());
+                } else {
+                    favorites.remove(&skin.name);
+                }
+                match self.store.save_favorites(&favorites) {
+                    Ok(()) => (200, skin_json(skin, &favorites)),
+                    Err(e) => error(500, &format!("couldn't save favorites: {}", e)),
+                }
+            },
+            ("GET", ["skins", name, "tags"]) => match find_skin(&skins, name) {
+                Some(skin) => {
+                    (200, json!(self.store.load_tags().remove(&skin.name).unwrap_or_default()))
+                },
+                None => error(404, &format!("no skin named {:?}", name)),
+            },
+            ("PUT" | "DELETE", ["skins", name, "tags", tag]) => {
+                let Some(skin) = find_skin(&skins, name) else {
+                    return error(404, &format!("no skin named {:?}", name));
+                };
+                let tag = tag.trim();
+                if tag.is_empty() || tag.contains(char::is_whitespace) {
+                    return error(400, "tags must be a single word");
+                }
+                let mut tags = self.store.load_tags();
+                let custom = tags.entry(skin.name.clone()).or_default();
+                custom.retain(|t| !t.eq_ignore_ascii_case(tag));
+                if method == "PUT" {
+                    custom.push(tag.to_string());
+                }
+                let custom = custom.clone();
+                if custom.is_empty() {
+                    tags.remove(&skin.name);
+                }
+                match self.store.save_tags(&tags) {
+                    Ok(()) => (200, json!(custom)),
+                    Err(e) => error(500, &format!("couldn't save tags: {}", e)),
+                }
+            },
+            _ => error(404, &format!("no endpoint for {} {}", method, path)),
+        }
+    }
+
+    fn search(&self, skins: &[Skin], query: &str, favorites: &HashSet<String>) -> Vec<Skin> {
+        if query.trim().is_empty() {
+            return skins.to_vec();
+        }
+        let name_map = skins.iter().enumerate().map(|(i, s)| (s.name_lower.clone(), i)).collect();
+        let query = expand_aliases(query, &self.aliases).to_lowercase();
+        search_skins(skins, &name_map, &query, favorites, &self.profile)
+    }
+}
+
+fn find_skin<'a>(skins: &'a [Skin], name: &str) -> Option<&'a Skin> {
+    let name = name.to_lowercase();
+    skins.iter().find(|skin| skin.name_lower == name)
+}
+
+fn skin_json(skin: &Skin, favorites: &HashSet<String>) -> Value {
+    json!({
+        "name": skin.name,
+        "rarity": skin.rarity,
+        "event": skin.event,
+        "year": skin.year,
+        "tags": skin.tags,
+        "favorite": favorites.contains(&skin.name),
+    })
+}
+
+fn skins_json(skins: &[Skin], favorites: &HashSet<String>) -> Value {
+    Value::Array(skins.iter().map(|skin| skin_json(skin, favorites)).collect())
+}
+
+fn stats_json(stats: &SkinStats) -> Value {
+    json!({
+        "total": stats.total,
+        "favorites": stats.favorites,
+        "by_rarity": stats.by_rarity,
+        "by_event": stats.by_event,
+        "by_year": stats.by_year,
+        "by_tag": stats.by_tag,
+        "favorites_by_rarity": stats.favorites_by_rarity,
+    })
+}
+
+fn error(status: u16, message: &str) -> (u16, Value) {
+    (status, json!({ "error": message }))
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Decodes `%XX` escapes in a URL path segment or query value, where `+` also means a space.
+fn decode(value: &str, plus_as_space: bool) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (escaped, bytes[i]) {
+            (Some(byte), _) => {
+                out.push(byte);
+                i += 2;
+            },
+            (None, b'+') if plus_as_space => out.push(b' '),
+            (None, byte) => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
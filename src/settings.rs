@@ -0,0 +1,50 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::config_file;
+
+/// TUI preferences that aren't key bindings or ranking profiles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Start in vim-style normal mode, where plain keys navigate instead of typing.
+    pub vim_mode: bool,
+    /// Percentage of the results area the table takes next to (or above) the detail panel.
+    pub table_percent: u16,
+    pub show_suggestions: bool,
+    /// Terminals narrower than this stack the detail panel under the table.
+    pub stack_below_width: u16,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            vim_mode: false,
+            table_percent: 60,
+            show_suggestions: true,
+            stack_below_width: 100,
+        }
+    }
+}
+
+pub fn get_settings_path() -> PathBuf {
+    config_file("settings.json")
+}
+
+pub fn load_settings(path: &Path) -> Settings {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_settings(settings: &Settings, path: &Path) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(settings)?)
+}
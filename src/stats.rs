@@ -0,0 +1,66 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{rarity_tier, Skin};
+
+/// Counts behind the statistics view, for the whole catalog or a filtered result set.
+pub struct SkinStats {
+    pub total: usize,
+    pub favorites: usize,
+    /// Ordered by rarity tier.
+    pub by_rarity: Vec<(String, u64)>,
+    /// Ordered by count, most common first.
+    pub by_event: Vec<(String, u64)>,
+    /// Ordered by year, with undated skins last.
+    pub by_year: Vec<(String, u64)>,
+    /// Ordered by count, most common first.
+    pub by_tag: Vec<(String, u64)>,
+    /// Ordered by rarity tier.
+    pub favorites_by_rarity: Vec<(String, u64)>,
+}
+
+impl SkinStats {
+    pub fn compute(skins: &[Skin], favorites: &HashSet<String>) -> Self {
+        let by_rarity = count_by_rarity(skins.iter());
+        let favorites_by_rarity =
+            count_by_rarity(skins.iter().filter(|skin| favorites.contains(&skin.name)));
+
+        let mut by_event = count(skins.iter().map(|skin| skin.event.clone()));
+        by_event.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let mut by_year = count(skins.iter().map(|skin| {
+            if skin.year_str.is_empty() {
+                "N/A".to_string()
+            } else {
+                skin.year_str.clone()
+            }
+        }));
+        by_year.sort_by_key(|(year, _)| year.parse::<u32>().unwrap_or(u32::MAX));
+
+        let mut by_tag = count(skins.iter().flat_map(|skin| skin.tags_lower.iter().cloned()));
+        by_tag.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        SkinStats {
+            total: skins.len(),
+            favorites: skins.iter().filter(|skin| favorites.contains(&skin.name)).count(),
+            by_rarity,
+            by_event,
+            by_year,
+            by_tag,
+            favorites_by_rarity,
+        }
+    }
+}
+
+fn count(values: impl Iterator<Item = String>) -> Vec<(String, u64)> {
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts.into_iter().collect()
+}
+
+fn count_by_rarity<'a>(skins: impl Iterator<Item = &'a Skin>) -> Vec<(String, u64)> {
+    let mut counts = count(skins.map(|skin| skin.rarity.clone()));
+    counts.sort_by_key(|(rarity, _)| rarity_tier(&rarity.to_lowercase()));
+    counts
+}
@@ -0,0 +1,65 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs, io,
+    path::PathBuf,
+};
+
+use crate::{config::config_file, Skin};
+
+/// The user's own data: favorites, and tags they've added to skins. The TUI and `pola serve`
+/// read and write the same files, so changes made through one show up in the other.
+pub struct Store {
+    pub favorites_path: PathBuf,
+    pub tags_path: PathBuf,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Store {
+            favorites_path: PathBuf::from("favorites.txt"),
+            tags_path: config_file("custom_tags.json"),
+        }
+    }
+}
+
+impl Store {
+    pub fn load_favorites(&self) -> HashSet<String> {
+        fs::read_to_string(&self.favorites_path)
+            .map(|content| {
+                content.lines().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn save_favorites(&self, favorites: &HashSet<String>) -> io::Result<()> {
+        let content = favorites.iter().map(|s| s.as_str()).collect::<Vec<_>>().join("\n");
+        fs::write(&self.favorites_path, content)
+    }
+
+    /// Custom tags by skin name, e.g. `{"Void Lord": ["traded"]}`.
+    pub fn load_tags(&self) -> BTreeMap<String, Vec<String>> {
+        fs::read_to_string(&self.tags_path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_tags(&self, tags: &BTreeMap<String, Vec<String>>) -> io::Result<()> {
+        if let Some(dir) = self.tags_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let content = serde_json::to_string_pretty(tags)?;
+        fs::write(&self.tags_path, content)
+    }
+}
+
+/// Adds each skin's custom tags after its catalog ones, skipping tags it already has.
+pub fn apply_custom_tags(skins: &mut [Skin], tags: &BTreeMap<String, Vec<String>>) {
+    for skin in skins {
+        for tag in tags.get(&skin.name).into_iter().flatten() {
+            if skin.tags_lower.insert(tag.to_lowercase()) {
+                skin.tags.push(tag.clone());
+            }
+        }
+    }
+}
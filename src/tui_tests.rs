@@ -0,0 +1,468 @@
+//! Golden snapshots of the TUI. Each test plays scripted events through `handle_event`, like the
+//! main loop does, and compares the rendered screen plus where clickable widgets landed against
+//! `tests/snapshots/<name>.txt`. Run with `UPDATE_SNAPSHOTS=1` to rewrite the snapshots after an
+//! intended change, then review the diff.
+
+use std::{
+    fs,
+    ops::{Deref, DerefMut},
+    path::PathBuf,
+};
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::{backend::TestBackend, Terminal};
+
+use super::*;
+use crate::script::{parse_script, screen_text, ScriptError};
+
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 30;
+
+/// An app on the default configuration, so snapshots don't depend on the user's files. Its data
+/// directory is removed when it's dropped.
+struct TestApp {
+    app: AppState,
+    dir: PathBuf,
+}
+
+impl Deref for TestApp {
+    type Target = AppState;
+
+    fn deref(&self) -> &AppState {
+        &self.app
+    }
+}
+
+impl DerefMut for TestApp {
+    fn deref_mut(&mut self) -> &mut AppState {
+        &mut self.app
+    }
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn test_app(name: &str) -> TestApp {
+    let dir = std::env::temp_dir().join(format!("pola-tui-{}-{}", name, std::process::id()));
+    let mut app = AppState::with_config(UserConfig::defaults(&dir));
+    // Today's pick would change the search bar every day
+    app.daily_skin = None;
+    app.headless = true;
+    app.update_search();
+    fs::create_dir_all(&dir).unwrap();
+    TestApp { app, dir }
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn ctrl(c: char) -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+}
+
+fn typed(text: &str) -> Vec<Event> {
+    text.chars().map(|c| key(KeyCode::Char(c))).collect()
+}
+
+fn click(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+/// Plays `events` with a frame drawn before each, like the main loop. Dialogs read their keys
+/// from the same queue, and fail with `UnexpectedEof` if it runs out while they're open.
+fn play(
+    app: &mut AppState,
+    terminal: &mut Terminal<TestBackend>,
+    events: Vec<Event>,
+) -> io::Result<()> {
+    app.events = EventSource::Scripted(events.into());
+    loop {
+        terminal.draw(|f| ui(f, app))?;
+        match &app.events {
+            EventSource::Scripted(events) if !events.is_empty() => {},
+            _ => return Ok(()),
+        }
+        if let Some(event) = app.poll_event()? {
+            app.handle_event(event, terminal)?;
+        }
+    }
+}
+
+fn rect(area: Rect) -> String {
+    format!("{},{} {}x{}", area.x, area.y, area.width, area.height)
+}
+
+/// The last frame, without trailing spaces, followed by the click targets from `app.layout`.
+fn screen(app: &AppState, terminal: &Terminal<TestBackend>) -> String {
+    let mut out = screen_text(terminal.backend().buffer());
+
+    let layout = &app.layout;
+    out.push_str("---\n");
+    out.push_str(&format!("search: {}\n", rect(layout.search)));
+    out.push_str(&format!("suggestions: {}\n", rect(layout.suggestions)));
+    let header: Vec<String> = layout
+        .header
+        .iter()
+        .map(|(area, field)| format!("{} {}", field.map_or("-", |f| f.label()), rect(*area)))
+        .collect();
+    out.push_str(&format!("header: {}\n", header.join(", ")));
+    out.push_str(&format!("rows: {} from {}\n", rect(layout.rows), layout.row_offset));
+    out.push_str(&format!("tags: {}\n", layout.tags.len()));
+    out.push_str(&format!("pages: {} {}\n", rect(layout.prev_page), rect(layout.next_page)));
+    let chips: Vec<&str> = layout.chips.iter().map(|(_, chip)| chip.as_str()).collect();
+    out.push_str(&format!("chips: {}\n", chips.join(", ")));
+    out.push_str(&format!("divider: {}\n", rect(layout.divider)));
+    out
+}
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path: PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "tests", "snapshots", &format!("{}.txt", name)]
+            .iter()
+            .collect();
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("no snapshot at {}, run with UPDATE_SNAPSHOTS=1 to create it", path.display())
+    });
+    assert_eq!(actual, expected, "{} no longer matches its snapshot", name);
+}
+
+fn run(name: &str, events: Vec<Event>) -> (TestApp, Terminal<TestBackend>) {
+    let mut app = test_app(name);
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    play(&mut app, &mut terminal, events).unwrap();
+    assert_snapshot(name, &screen(&app, &terminal));
+    (app, terminal)
+}
+
+#[test]
+fn startup() {
+    run("startup", Vec::new());
+}
+
+#[test]
+fn typing_a_query() {
+    let (app, _) = run("typing_a_query", typed("pink summ"));
+    assert_eq!(app.input, "pink summ");
+}
+
+#[test]
+fn tab_cycles_and_right_accepts_suggestions() {
+    let mut events = typed("chr");
+    events.extend([key(KeyCode::Tab), key(KeyCode::Tab)]);
+    let (app, _) = run("tab_cycles_suggestions", events.clone());
+    assert_eq!(app.suggestion_index, 2);
+    let highlighted = app.suggestion_list[2].clone();
+
+    events.push(key(KeyCode::Right));
+    let (app, _) = run("right_accepts_suggestion", events);
+    assert_eq!(app.input.trim_end(), highlighted);
+}
+
+#[test]
+fn paging() {
+    let events = vec![key(KeyCode::PageDown), key(KeyCode::PageDown), key(KeyCode::Down)];
+    let (app, _) = run("paging", events);
+    assert_eq!(app.current_page, 2);
+    assert_eq!(app.table_state.selected(), Some(1));
+}
+
+#[test]
+fn ctrl_f_favorites_the_selected_skin() {
+    let mut events = typed("void lord");
+    events.push(ctrl('f'));
+    let (app, _) = run("favorite", events);
+    assert!(app.favorites.contains("Void Lord"));
+    assert_eq!(app.store.load_favorites(), app.favorites);
+}
+
+#[test]
+fn clicking_a_header_sorts_by_it() {
+    let mut app = test_app("header_click");
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    play(&mut app, &mut terminal, Vec::new()).unwrap();
+    let (area, _) = *app
+        .layout
+        .header
+        .iter()
+        .find(|(_, field)| *field == Some(SortField::Rarity))
+        .expect("the rarity header is clickable");
+    play(&mut app, &mut terminal, vec![click(area.x + 1, area.y)]).unwrap();
+    assert_eq!(app.sort_keys, [SortKey { field: SortField::Rarity, descending: true }]);
+    assert_snapshot("header_click", &screen(&app, &terminal));
+}
+
+#[test]
+fn help_page() {
+    let mut app = test_app("help");
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    // The help page is still open when the script runs out, so its last frame is on screen
+    let error = play(&mut app, &mut terminal, vec![ctrl('h'), key(KeyCode::Down)]).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(app.help_state.selected(), Some(1));
+    assert_snapshot("help", &screen(&app, &terminal));
+
+    play(&mut app, &mut terminal, vec![ctrl('h'), key(KeyCode::Esc)]).unwrap();
+    assert!(!app.should_exit);
+}
+
+#[test]
+fn suggestions_skip_terms_already_typed() {
+    let mut app = test_app("suggestions");
+    let mut inputs: Vec<String> = app
+        .all_terms
+        .keys()
+        .filter(|term| !term.contains(' '))
+        .map(|term| format!("{} {}", term, term.chars().take(2).collect::<String>()))
+        .collect();
+    inputs.extend(
+        ["halloween case h", "summer case ca", "tag:popular pop", "-event:summer summ", "pink Pi"]
+            .map(String::from),
+    );
+    for input in inputs {
+        app.input = input.clone();
+        app.update_search();
+        let typed: Vec<String> = input
+            .split_whitespace()
+            .rev()
+            .skip(1)
+            .map(|part| part.rsplit(':').next().unwrap().to_lowercase())
+            .collect();
+        for suggestion in &app.suggestion_list {
+            let repeated =
+                suggestion.split_whitespace().all(|word| typed.iter().any(|t| t == word));
+            assert!(!repeated, "suggested {:?} for {:?}", suggestion, input);
+        }
+    }
+}
+
+#[test]
+fn chips_narrow_to_the_skin_they_came_from() {
+    let mut app = test_app("chips");
+    // A custom tag with a space, which its chip has to quote
+    let skin = app.skins.iter_mut().find(|skin| skin.name == "Void Lord").unwrap();
+    skin.tags.push("All Case".to_string());
+    skin.tags_lower.insert("all case".to_string());
+    app.update_search();
+    assert!(app.select_skin("Void Lord"));
+    let chips = app.detail_chips();
+    assert_eq!(chips, ["rarity:pink", "tag:case", "tag:popular", "tag:\"all case\""]);
+
+    let mut count = app.results.len();
+    for chip in &chips {
+        app.add_query_term(chip);
+        assert!(app.results.iter().any(|skin| skin.name == "Void Lord"), "after {}", chip);
+        assert!(app.results.len() <= count, "{} widened {:?}", chip, app.input);
+        count = app.results.len();
+    }
+    let input = app.input.clone();
+    app.add_query_term(&chips[3]);
+    assert_eq!(app.input, input);
+}
+
+#[test]
+fn every_facet_filters_to_its_count() {
+    let mut app = test_app("facets");
+    app.toggle_facets();
+    let counts = app.facet_counts.clone();
+    for (i, (field, value)) in app.facets.clone().iter().enumerate() {
+        app.toggle_facet(i);
+        assert!(app.is_facet_checked(i), "{}:{} isn't checked by {:?}", field, value, app.input);
+        assert!(counts[i] > 0, "{}:{} matches nothing", field, value);
+        assert_eq!(app.results.len(), counts[i], "{}:{} gave {:?}", field, value, app.input);
+        app.toggle_facet(i);
+        assert_eq!(app.input, "");
+    }
+
+    // Facets in one group are alternatives
+    let facet = |value: &str| app.facets.iter().position(|(_, v)| v == value).unwrap();
+    let (pink, red) = (facet("pink"), facet("red"));
+    app.toggle_facet(pink);
+    app.toggle_facet(red);
+    assert_eq!(app.input, "rarity:pink,red ");
+    assert_eq!(app.results.len(), counts[pink] + counts[red]);
+    app.toggle_facet(pink);
+    assert_eq!(app.input, "rarity:red ");
+}
+
+fn run_script(app: &mut AppState, script: &str) -> Result<Terminal<TestBackend>, ScriptError> {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    let steps = parse_script(script)?;
+    app.run_script(&steps, &mut terminal)?;
+    Ok(terminal)
+}
+
+#[test]
+fn a_script_plays_keys_actions_and_assertions() {
+    let mut app = test_app("script");
+    let script = r#"
+        # Favorite Void Lord, then list the favorites
+        type "void lord"
+        assert selected == "Void Lord"
+        action toggle_favorite
+        key Ctrl+l
+        assert input == ""
+        type "favorite"
+        assert results.len == 1
+        assert favorites.len == 1
+        # The help page reads the Esc that follows it
+        key Ctrl+h
+        key Esc
+        assert mode == insert
+    "#;
+    let mut terminal = run_script(&mut app, script).unwrap();
+    assert!(matches!(app.events, EventSource::Terminal));
+    terminal.draw(|f| ui(f, &mut app)).unwrap();
+    assert_snapshot("script", &screen(&app, &terminal));
+}
+
+#[test]
+fn script_errors_name_their_line() {
+    let mut app = test_app("script_errors");
+    let error = run_script(&mut app, "type void\n\nassert results.len > 5").unwrap_err();
+    assert_eq!(error.line, 3);
+    assert_eq!(error.message, r#"expected results.len > "5", but it was "1""#);
+
+    let error = run_script(&mut app, "key Ctrl+h\nassert page == 1").unwrap_err();
+    assert_eq!((error.line, error.message.as_str()), (1, "a dialog is still waiting for keys"));
+
+    for (script, message) in [
+        ("jump", r#"unknown command "jump""#),
+        ("key Hyper+x", r#"unknown key "Hyper+x""#),
+        ("action fly", r#"unknown action "fly""#),
+        ("assert results == 3", "unknown field \"results\""),
+        ("assert input ~ void", r#"unknown comparison "~""#),
+        ("type \"void", "bad string \"void"),
+    ] {
+        let error = parse_script(&format!("# comment\n{}", script)).err().unwrap();
+        assert_eq!(error.line, 2);
+        assert!(error.message.starts_with(message), "{} for {:?}", error.message, script);
+    }
+}
+
+#[test]
+fn a_recorded_macro_replays_what_was_typed() {
+    let alt = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT));
+    let mut app = test_app("record");
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    let mut events = vec![alt('m')];
+    events.extend(typed("void lord"));
+    events.extend([ctrl('f'), alt('c'), ctrl('l')]);
+    events.extend(typed("favorite"));
+    events.extend([ctrl('h'), key(KeyCode::Esc), alt('m')]);
+    play(&mut app, &mut terminal, events).unwrap();
+    assert!(app.recording.is_none());
+    assert!(app.status_message.as_ref().is_some_and(|message| message.contains("recorded.txt")));
+
+    let script = fs::read_to_string(app.macros_dir.join("recorded.txt")).unwrap();
+    assert_eq!(
+        script,
+        "type \"void lord\"\nkey Ctrl+f\nkey Alt+c\nkey Ctrl+l\ntype \"favorite\"\nkey Ctrl+h\n\
+         key Esc\n"
+    );
+    let mut replayed = test_app("record_replay");
+    run_script(&mut replayed, &script).unwrap();
+    assert_eq!(replayed.input, app.input);
+    assert_eq!(replayed.favorites, app.favorites);
+}
+
+#[test]
+fn alt_r_copies_the_page_on_screen() {
+    let mut app = test_app("copy_page");
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    let events = vec![
+        key(KeyCode::PageDown),
+        Event::Key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::ALT)),
+    ];
+    play(&mut app, &mut terminal, events).unwrap();
+    let rows = app.items_per_page;
+    let (message, _) = app.toast.clone().unwrap();
+    assert_eq!(message, format!("Copied page 2 ({} rows) as TSV", rows));
+}
+
+#[test]
+fn a_typo_is_corrected_only_when_the_query_finds_nothing() {
+    let mut app = test_app("typos");
+    app.input = "cupdi".to_string();
+    app.update_search();
+    assert_eq!(app.correction.as_deref(), Some("cupid"));
+    assert!(app.results.iter().any(|skin| skin.name == "Cupid"));
+
+    app.input = "void lord".to_string();
+    app.update_search();
+    assert_eq!(app.correction, None);
+
+    // A correction that finds nothing either isn't shown
+    app.input = "cupdi year:2019".to_string();
+    app.update_search();
+    assert_eq!(app.correction, None);
+    assert!(app.results.is_empty());
+}
+
+#[test]
+fn layout_changes_save_next_to_the_test_data() {
+    let right = Event::Key(KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL));
+    let mut app = test_app("settings");
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    play(&mut app, &mut terminal, vec![right.clone()]).unwrap();
+    let saved = fs::read_to_string(&app.settings_path).unwrap();
+    assert!(saved.contains(&format!("\"table_percent\": {}", app.settings.table_percent)));
+
+    // A settings file that can't be written is reported instead of panicking
+    app.settings_path = app.settings_path.join("settings.json");
+    play(&mut app, &mut terminal, vec![right]).unwrap();
+    assert!(app.status_message.as_ref().unwrap().starts_with("Couldn't save settings"));
+}
+
+#[test]
+fn a_long_chat_toast_is_cut_at_the_pane_width() {
+    let name = "Crème Brûlée Étoilée Décadence Suprême Über Edition";
+    let mut app = test_app("chat_toast");
+    app.results.insert(
+        0,
+        Skin {
+            name: name.to_string(),
+            name_lower: name.to_lowercase(),
+            rarity: "Pink".to_string(),
+            rarity_lower: "pink".to_string(),
+            event: "Valentine's Case".to_string(),
+            event_lower: "valentine's case".to_string(),
+            year: Some(2024),
+            year_str: "2024".to_string(),
+            tags: Vec::new(),
+            tags_lower: Default::default(),
+        },
+    );
+    app.table_state.select(Some(0));
+    let width = 48;
+    let mut terminal = Terminal::new(TestBackend::new(width, HEIGHT)).unwrap();
+    let alt_d = Event::Key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::ALT));
+    play(&mut app, &mut terminal, vec![alt_d]).unwrap();
+
+    let (message, _) = app.toast.clone().unwrap();
+    assert_eq!(message, format!("Copied {} for chat", name));
+    let text = screen_text(terminal.backend().buffer());
+    let y = text.lines().position(|line| line.contains("Copied")).unwrap() as u16;
+    let cells: Vec<&str> =
+        (0..width).map(|x| terminal.backend().buffer().get(x, y).symbol.as_str()).collect();
+    // Cut at the pane width between whole characters, rather than wrapped onto another row
+    assert!(cells.iter().all(|cell| cell.chars().count() == 1), "{:?}", cells);
+    let status = cells.concat();
+    assert!(format!(" ✓ {} ", message).contains(status.trim()), "{:?}", status);
+    assert!(!text.contains("for chat"));
+}
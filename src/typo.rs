@@ -0,0 +1,121 @@
+use crate::search::split_query;
+
+/// Optimal string alignment distance: insertions, deletions, substitutions and adjacent
+/// transpositions ("cupdi" -> "cupid") each cost one edit.
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dist = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dist[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            dist[i][j] =
+                (dist[i - 1][j] + 1).min(dist[i][j - 1] + 1).min(dist[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                dist[i][j] = dist[i][j].min(dist[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    dist[a.len()][b.len()]
+}
+
+/// How many edits a term of `len` characters may be away from a correction. Very short terms
+/// are left alone, since almost any word is one edit away from them.
+fn max_edits(len: usize) -> usize {
+    match len {
+        0..=2 => 0,
+        3..=5 => 1,
+        _ => 2,
+    }
+}
+
+/// The vocabulary word closest to `term`, if one is within the allowed edit distance.
+pub fn closest_term<'v>(term: &str, vocabulary: &[&'v str]) -> Option<&'v str> {
+    let allowed = max_edits(term.chars().count());
+    if allowed == 0 {
+        return None;
+    }
+    vocabulary
+        .iter()
+        .map(|&word| (damerau_levenshtein(term, word), word))
+        .filter(|&(distance, _)| distance > 0 && distance <= allowed)
+        .min()
+        .map(|(_, word)| word)
+}
+
+/// Rewrites the free-text terms of `query` that have no direct hits to their closest vocabulary
+/// word. Returns `None` when nothing needed (or could get) a correction.
+pub fn correct_query(
+    query: &str,
+    vocabulary: &[&str],
+    has_hits: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let mut corrected = false;
+    let parts: Vec<String> = split_query(query)
+        .into_iter()
+        .map(|part| {
+            let term = part.to_lowercase();
+            let is_free_text = !term.contains(':') && !term.starts_with('/');
+            if is_free_text && !has_hits(&term) {
+                if let Some(word) = closest_term(&term, vocabulary) {
+                    corrected = true;
+                    return word.to_string();
+                }
+            }
+            part.to_string()
+        })
+        .collect();
+
+    corrected.then(|| parts.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_transposition_is_one_edit() {
+        assert_eq!(damerau_levenshtein("cupdi", "cupid"), 1);
+        assert_eq!(damerau_levenshtein("vodi", "void"), 1);
+        assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(damerau_levenshtein("", ""), 0);
+        assert_eq!(damerau_levenshtein("", "void"), 4);
+        assert_eq!(damerau_levenshtein("void", ""), 4);
+        assert_eq!(closest_term("", &["void"]), None);
+        assert_eq!(correct_query("", &["void"], |_| false), None);
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        assert_eq!(damerau_levenshtein("crème", "creme"), 1);
+        assert_eq!(damerau_levenshtein("ñandú", "ñadnú"), 1);
+        assert_eq!(closest_term("ñadnú", &["ñandú", "void"]), Some("ñandú"));
+    }
+
+    #[test]
+    fn nothing_within_the_threshold() {
+        let vocabulary = ["void", "lord", "periastron"];
+        // Two edits away, but four-letter terms only get one
+        assert_eq!(closest_term("vxyd", &vocabulary), None);
+        // Terms this short are never corrected
+        assert_eq!(closest_term("vo", &vocabulary), None);
+        assert_eq!(correct_query("zzzzzz lord", &vocabulary, |_| false), None);
+        // Only terms without hits get corrected
+        assert_eq!(
+            correct_query("lrod periastorn", &vocabulary, |term| term == "lrod"),
+            Some("lrod periastron".to_string())
+        );
+    }
+}
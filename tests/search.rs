@@ -0,0 +1,159 @@
+//! Search behavior against the real catalog: a golden corpus of queries with their expected
+//! ranking in `fixtures/search_corpus.json`, and properties checked over every filter the
+//! catalog can produce. Free terms rank with any of them matching, so "adding never widens"
+//! is about `field:value` filters and exclusions, which every result has to pass.
+
+use std::collections::HashSet;
+
+use pola::SearchEngine;
+use serde_json::Value;
+
+const CORPUS: &str = include_str!("fixtures/search_corpus.json");
+
+fn search(engine: &SearchEngine, query: &str) -> Vec<Value> {
+    serde_json::from_str(&engine.search(query, "[]")).unwrap()
+}
+
+fn names(results: &[Value]) -> Vec<String> {
+    results.iter().map(|skin| skin["name"].as_str().unwrap().to_string()).collect()
+}
+
+fn lower(skin: &Value, field: &str) -> String {
+    skin[field].as_str().unwrap_or_default().to_lowercase()
+}
+
+/// Mirrors `matches_filter` from the JSON a result is returned as.
+fn passes(skin: &Value, field: &str, value: &str) -> bool {
+    match field {
+        "rarity" => lower(skin, "rarity") == value,
+        "event" => lower(skin, "event").contains(value),
+        "year" => skin["year"].as_u64().is_some_and(|year| year.to_string() == value),
+        "tag" => skin["tags"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|tag| tag.as_str().unwrap().to_lowercase() == value),
+        _ => unreachable!("no {} filter", field),
+    }
+}
+
+/// One `field:value` filter for every rarity, year, single-word tag and event word in the catalog.
+fn catalog_filters(engine: &SearchEngine) -> Vec<String> {
+    let catalog: Vec<Value> = serde_json::from_str(&engine.catalog()).unwrap();
+    let mut filters = Vec::new();
+    for skin in &catalog {
+        filters.push(format!("rarity:{}", lower(skin, "rarity")));
+        if let Some(year) = skin["year"].as_u64() {
+            filters.push(format!("year:{}", year));
+        }
+        for tag in skin["tags"].as_array().unwrap() {
+            let tag = tag.as_str().unwrap().to_lowercase();
+            if !tag.contains(' ') {
+                filters.push(format!("tag:{}", tag));
+            }
+        }
+        for word in lower(skin, "event").split_whitespace() {
+            if word.chars().all(char::is_alphanumeric) {
+                filters.push(format!("event:{}", word));
+            }
+        }
+    }
+    filters.sort();
+    filters.dedup();
+    filters
+}
+
+/// Queries of one or two filters, either of which may be an exclusion.
+fn filter_queries(filters: &[String]) -> Vec<String> {
+    let mut queries: Vec<String> = filters.to_vec();
+    for (i, first) in filters.iter().enumerate() {
+        for second in filters.iter().skip(i + 1).step_by(7) {
+            queries.push(format!("{} {}", first, second));
+            queries.push(format!("{} -{}", first, second));
+        }
+    }
+    queries
+}
+
+#[test]
+fn golden_corpus() {
+    let engine = SearchEngine::new();
+    let corpus: Vec<Value> =
+        serde_json::from_str(CORPUS).expect("search_corpus.json is valid JSON");
+    for case in corpus {
+        let query = case["query"].as_str().unwrap();
+        let results: Vec<Value> =
+            serde_json::from_str(&engine.search(query, &case["favorites"].to_string())).unwrap();
+        let expected: Vec<String> = serde_json::from_value(case["results"].clone()).unwrap();
+        assert_eq!(names(&results), expected, "query {:?}", query);
+    }
+}
+
+#[test]
+fn results_pass_every_filter() {
+    let engine = SearchEngine::new();
+    for query in filter_queries(&catalog_filters(&engine)) {
+        let mut required: Vec<(&str, &str)> = Vec::new();
+        let mut excluded: Vec<(&str, &str)> = Vec::new();
+        for part in query.split_whitespace() {
+            let (field, value) = part.split_once(':').unwrap();
+            match field.strip_prefix('-') {
+                Some(field) => excluded.push((field, value)),
+                None => required.push((field, value)),
+            }
+        }
+        for skin in search(&engine, &query) {
+            for (field, value) in &required {
+                assert!(passes(&skin, field, value), "{} in {:?}", skin["name"], query);
+            }
+            for (field, value) in &excluded {
+                assert!(!passes(&skin, field, value), "{} in {:?}", skin["name"], query);
+            }
+        }
+    }
+}
+
+#[test]
+fn results_are_deduplicated() {
+    let engine = SearchEngine::new();
+    let catalog: Vec<Value> = serde_json::from_str(&engine.catalog()).unwrap();
+    let mut queries = filter_queries(&catalog_filters(&engine));
+    queries.extend(names(&catalog).iter().map(|name| name.to_lowercase()));
+    queries.extend(["a", "e", "case", "pink summ", "void lord 2023"].map(String::from));
+    for query in queries {
+        let names = names(&search(&engine, &query));
+        let unique: HashSet<&String> = names.iter().collect();
+        assert_eq!(unique.len(), names.len(), "duplicates for {:?}", query);
+    }
+}
+
+#[test]
+fn adding_a_filter_never_widens() {
+    let engine = SearchEngine::new();
+    let filters = catalog_filters(&engine);
+    let mut bases = filters.clone();
+    bases.extend(["rarity:pink void", "event:halloween a", "tag:popular 202"].map(String::from));
+    for base in &bases {
+        let before: HashSet<String> = names(&search(&engine, base)).into_iter().collect();
+        for filter in &filters {
+            for added in [filter.clone(), format!("-{}", filter)] {
+                let query = format!("{} {}", base, added);
+                for name in names(&search(&engine, &query)) {
+                    assert!(before.contains(&name), "{:?} added {} to {:?}", added, name, base);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn an_exact_name_ranks_its_skin_first() {
+    let engine = SearchEngine::new();
+    let catalog: Vec<Value> = serde_json::from_str(&engine.catalog()).unwrap();
+    for name in names(&catalog) {
+        let results = names(&search(&engine, &name.to_lowercase()));
+        assert_eq!(results.first(), Some(&name), "searching {:?}", name);
+    }
+    // Naming a skin doesn't get it past the rest of the query
+    assert!(names(&search(&engine, "void lord rarity:red")).is_empty());
+}
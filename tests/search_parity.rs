@@ -0,0 +1,71 @@
+//! Checks `SearchEngine` against results recorded from a native build, so the wasm build the
+//! website loads ranks, filters and completes exactly like the TUI.
+//!
+//! Native: `cargo test --test search_parity`
+//! Headless wasm: `wasm-pack test --node -- --features wasm --test search_parity`
+
+use pola::SearchEngine;
+use serde_json::Value;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+const GOLDEN: &str = include_str!("fixtures/search_golden.json");
+
+fn golden() -> Vec<Value> {
+    serde_json::from_str(GOLDEN).expect("search_golden.json is valid JSON")
+}
+
+fn names(results: &str) -> Vec<String> {
+    let results: Vec<Value> = serde_json::from_str(results).unwrap();
+    results.iter().map(|skin| skin["name"].as_str().unwrap().to_string()).collect()
+}
+
+#[test]
+fn search_matches_native_results() {
+    let engine = SearchEngine::new();
+    for case in golden() {
+        let query = case["query"].as_str().unwrap();
+        let favorites = case["favorites"].to_string();
+        let expected: Vec<String> = serde_json::from_value(case["results"].clone()).unwrap();
+        assert_eq!(names(&engine.search(query, &favorites)), expected, "query {:?}", query);
+    }
+}
+
+#[test]
+fn suggest_matches_native_results() {
+    let engine = SearchEngine::new();
+    for case in golden() {
+        let query = case["query"].as_str().unwrap();
+        let favorites = case["favorites"].to_string();
+        let expected: Vec<String> = serde_json::from_value(case["suggestions"].clone()).unwrap();
+        let suggestions: Vec<String> =
+            serde_json::from_str(&engine.suggest(query, &favorites)).unwrap();
+        assert_eq!(suggestions, expected, "query {:?}", query);
+    }
+}
+
+#[test]
+fn explain_totals_follow_result_order() {
+    let engine = SearchEngine::new();
+    for case in golden() {
+        let query = case["query"].as_str().unwrap();
+        let favorites = case["favorites"].to_string();
+        let totals: Vec<i64> = names(&engine.search(query, &favorites))
+            .iter()
+            .map(|name| {
+                let explained: Value =
+                    serde_json::from_str(&engine.explain(query, name, &favorites)).unwrap();
+                explained["total"].as_i64().unwrap()
+            })
+            .collect();
+        assert!(totals.windows(2).all(|pair| pair[0] >= pair[1]), "query {:?}", query);
+    }
+}
+
+#[test]
+fn catalog_lists_every_skin() {
+    let engine = SearchEngine::new();
+    assert_eq!(names(&engine.catalog()), names(&engine.search("", "[]")));
+    assert_eq!(engine.explain("void", "no such skin", "[]"), "null");
+}
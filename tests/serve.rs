@@ -0,0 +1,136 @@
+//! Drives `pola serve` over a loopback socket, with favorites and custom tags kept in a scratch
+//! directory and the built-in ranking, so none of the user's own files are read or written.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use pola::{RankingProfile, Server, Store};
+use serde_json::Value;
+
+fn start(name: &str) -> (SocketAddr, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("pola-serve-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let store = Store {
+        favorites_path: dir.join("favorites.txt"),
+        tags_path: dir.join("custom_tags.json"),
+    };
+    let server =
+        Server::bind("127.0.0.1:0", store, RankingProfile::default(), HashMap::new()).unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+    (addr, dir)
+}
+
+fn request(addr: SocketAddr, method: &str, target: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        method, target
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+fn names(skins: &Value) -> Vec<&str> {
+    skins.as_array().unwrap().iter().map(|skin| skin["name"].as_str().unwrap()).collect()
+}
+
+#[test]
+fn serves_the_catalog() {
+    let (addr, _) = start("catalog");
+
+    let (status, skins) = request(addr, "GET", "/skins");
+    assert_eq!(status, 200);
+    assert!(names(&skins).contains(&"Void Lord"));
+
+    let (status, skin) = request(addr, "GET", "/skins/void%20lord");
+    assert_eq!(status, 200);
+    assert_eq!(skin["name"], "Void Lord");
+    assert_eq!(skin["favorite"], false);
+
+    let (status, body) = request(addr, "GET", "/skins/not%20a%20skin");
+    assert_eq!(status, 404);
+    assert!(body["error"].is_string());
+
+    let (status, stats) = request(addr, "GET", "/stats");
+    assert_eq!(status, 200);
+    assert_eq!(stats["total"].as_u64().unwrap() as usize, skins.as_array().unwrap().len());
+
+    assert_eq!(request(addr, "GET", "/nowhere").0, 404);
+    assert_eq!(request(addr, "POST", "/skins").0, 404);
+}
+
+#[test]
+fn searches_and_suggests() {
+    let (addr, _) = start("search");
+
+    let (status, results) = request(addr, "GET", "/search?q=rarity%3Ateal+2024");
+    assert_eq!(status, 200);
+    let results = results.as_array().unwrap();
+    assert!(!results.is_empty());
+    assert!(results.iter().all(|skin| skin["rarity"] == "Teal"));
+
+    let (status, suggestions) = request(addr, "GET", "/suggest?q=chr");
+    assert_eq!(status, 200);
+    assert!(suggestions.as_array().unwrap().iter().any(|term| term == "christmas case"));
+}
+
+#[test]
+fn edits_favorites_and_tags() {
+    let (addr, dir) = start("store");
+
+    let (status, skin) = request(addr, "PUT", "/favorites/Void%20Lord");
+    assert_eq!(status, 200);
+    assert_eq!(skin["favorite"], true);
+    assert_eq!(request(addr, "GET", "/favorites").1, serde_json::json!(["Void Lord"]));
+    assert_eq!(fs::read_to_string(dir.join("favorites.txt")).unwrap(), "Void Lord");
+    assert_eq!(names(&request(addr, "GET", "/search?q=favorite").1), ["Void Lord"]);
+
+    assert_eq!(request(addr, "DELETE", "/favorites/void%20lord").1["favorite"], false);
+    assert_eq!(request(addr, "GET", "/favorites").1, serde_json::json!([]));
+    assert_eq!(request(addr, "PUT", "/favorites/not%20a%20skin").0, 404);
+
+    let (status, tags) = request(addr, "PUT", "/skins/Void%20Lord/tags/traded");
+    assert_eq!(status, 200);
+    assert_eq!(tags, serde_json::json!(["traded"]));
+    assert_eq!(request(addr, "GET", "/skins/Void%20Lord/tags").1, serde_json::json!(["traded"]));
+    let skin = request(addr, "GET", "/skins/Void%20Lord").1;
+    assert!(skin["tags"].as_array().unwrap().iter().any(|tag| tag == "traded"));
+    assert_eq!(names(&request(addr, "GET", "/search?q=tag%3Atraded").1), ["Void Lord"]);
+    assert_eq!(request(addr, "PUT", "/skins/Void%20Lord/tags/two%20words").0, 400);
+
+    assert_eq!(request(addr, "DELETE", "/skins/Void%20Lord/tags/TRADED").1, serde_json::json!([]));
+    assert_eq!(request(addr, "GET", "/skins/Void%20Lord/tags").1, serde_json::json!([]));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn a_stalled_client_times_out() {
+    let (addr, _) = start("stalled");
+
+    // Opens a request and never finishes it
+    let mut stalled = TcpStream::connect(addr).unwrap();
+    write!(stalled, "GET /skins HTTP/1.1\r\n").unwrap();
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    write!(stream, "GET /stats HTTP/1.1\r\nConnection: close\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).expect("the stalled connection blocked the server");
+    assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+    drop(stalled);
+}